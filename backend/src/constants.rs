@@ -3,6 +3,11 @@ use std::sync::LazyLock;
 
 pub const HEADER_USER_ID: &str = "X-User-Id";
 
+// Every pick slot in a league or mock draft is stored, so drafts are capped at
+// a realistic size.
+pub const MAX_DRAFT_TEAMS: i32 = 32;
+pub const MAX_DRAFT_ROUNDS: i32 = 40;
+
 pub static STATS_BY_POSITION: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
     LazyLock::new(|| {
         HashMap::from([
@@ -106,5 +111,262 @@ pub static STATS_BY_POSITION: LazyLock<HashMap<&'static str, Vec<&'static str>>>
                     "games",
                 ],
             ),
+            (
+                "dl",
+                vec![
+                    "tackles",
+                    "assists",
+                    "idp_sacks",
+                    "tackles_for_loss",
+                    "qb_hits",
+                    "passes_defended",
+                    "idp_int",
+                    "idp_fumbles_forced",
+                    "idp_fumbles_recovered",
+                    "idp_td",
+                    "games",
+                ],
+            ),
+            (
+                "lb",
+                vec![
+                    "tackles",
+                    "assists",
+                    "idp_sacks",
+                    "tackles_for_loss",
+                    "qb_hits",
+                    "passes_defended",
+                    "idp_int",
+                    "idp_fumbles_forced",
+                    "idp_fumbles_recovered",
+                    "idp_td",
+                    "games",
+                ],
+            ),
+            (
+                "db",
+                vec![
+                    "tackles",
+                    "assists",
+                    "idp_sacks",
+                    "tackles_for_loss",
+                    "qb_hits",
+                    "passes_defended",
+                    "idp_int",
+                    "idp_fumbles_forced",
+                    "idp_fumbles_recovered",
+                    "idp_td",
+                    "games",
+                ],
+            ),
+        ])
+    });
+
+// Projection pages list season totals only (no games, rates or long plays) and
+// start straight at the player column.
+pub static PROJECTIONS_BY_POSITION: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
+    LazyLock::new(|| {
+        HashMap::from([
+            (
+                "qb",
+                vec![
+                    "pass_att", "pass_cmp", "pass_yds", "pass_td", "pass_int", "rush_att",
+                    "rush_yds", "rush_td", "fumbles",
+                ],
+            ),
+            (
+                "rb",
+                vec![
+                    "rush_att",
+                    "rush_yds",
+                    "rush_td",
+                    "receptions",
+                    "rec_yds",
+                    "rec_td",
+                    "fumbles",
+                ],
+            ),
+            (
+                "wr",
+                vec![
+                    "receptions",
+                    "rec_yds",
+                    "rec_td",
+                    "rush_att",
+                    "rush_yds",
+                    "rush_td",
+                    "fumbles",
+                ],
+            ),
+            ("te", vec!["receptions", "rec_yds", "rec_td", "fumbles"]),
+            ("k", vec!["field_goals", "fg_att", "extra_points"]),
+            (
+                "dst",
+                vec![
+                    "sacks",
+                    "int",
+                    "fumbles_recovered",
+                    "fumbles_forced",
+                    "def_td",
+                    "safeties",
+                    "points_allowed",
+                    "yards_allowed",
+                ],
+            ),
+        ])
+    });
+
+pub const PROJECTED_GAMES: f64 = 17.0;
+
+// Game log tables lead with week, opponent and result columns, and each row is
+// a single game, so there's no games column.
+pub static GAME_LOG_STATS_BY_POSITION: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
+    LazyLock::new(|| {
+        HashMap::from([
+            (
+                "qb",
+                vec![
+                    "pass_cmp",
+                    "pass_att",
+                    "pass_cmp_pct",
+                    "pass_yds",
+                    "pass_yds_per_att",
+                    "pass_td",
+                    "pass_int",
+                    "pass_sacks",
+                    "rush_att",
+                    "rush_yds",
+                    "rush_yds_per_att",
+                    "rush_long",
+                    "rush_td",
+                    "fumbles",
+                ],
+            ),
+            (
+                "rb",
+                vec![
+                    "rush_att",
+                    "rush_yds",
+                    "rush_yds_per_att",
+                    "rush_long",
+                    "rush_td",
+                    "rec_tgt",
+                    "receptions",
+                    "rec_yds",
+                    "rec_yds_per_rec",
+                    "rec_long",
+                    "rec_td",
+                    "fumbles",
+                ],
+            ),
+            (
+                "wr",
+                vec![
+                    "rec_tgt",
+                    "receptions",
+                    "rec_yds",
+                    "rec_yds_per_rec",
+                    "rec_long",
+                    "rec_td",
+                    "rush_att",
+                    "rush_yds",
+                    "rush_yds_per_att",
+                    "rush_long",
+                    "rush_td",
+                    "fumbles",
+                ],
+            ),
+            (
+                "te",
+                vec![
+                    "rec_tgt",
+                    "receptions",
+                    "rec_yds",
+                    "rec_yds_per_rec",
+                    "rec_long",
+                    "rec_td",
+                    "rush_att",
+                    "rush_yds",
+                    "rush_yds_per_att",
+                    "rush_long",
+                    "rush_td",
+                    "fumbles",
+                ],
+            ),
+            (
+                "k",
+                vec![
+                    "field_goals",
+                    "fg_att",
+                    "fg_pct",
+                    "fg_long",
+                    "fg_1_19",
+                    "fg_20_29",
+                    "fg_30_39",
+                    "fg_40_49",
+                    "fg_50",
+                    "extra_points",
+                    "xp_att",
+                ],
+            ),
+            (
+                "dst",
+                vec![
+                    "sacks",
+                    "int",
+                    "fumbles_recovered",
+                    "fumbles_forced",
+                    "def_td",
+                    "safeties",
+                    "special_teams_td",
+                    "points_allowed",
+                    "yards_allowed",
+                ],
+            ),
+            (
+                "dl",
+                vec![
+                    "tackles",
+                    "assists",
+                    "idp_sacks",
+                    "tackles_for_loss",
+                    "qb_hits",
+                    "passes_defended",
+                    "idp_int",
+                    "idp_fumbles_forced",
+                    "idp_fumbles_recovered",
+                    "idp_td",
+                ],
+            ),
+            (
+                "lb",
+                vec![
+                    "tackles",
+                    "assists",
+                    "idp_sacks",
+                    "tackles_for_loss",
+                    "qb_hits",
+                    "passes_defended",
+                    "idp_int",
+                    "idp_fumbles_forced",
+                    "idp_fumbles_recovered",
+                    "idp_td",
+                ],
+            ),
+            (
+                "db",
+                vec![
+                    "tackles",
+                    "assists",
+                    "idp_sacks",
+                    "tackles_for_loss",
+                    "qb_hits",
+                    "passes_defended",
+                    "idp_int",
+                    "idp_fumbles_forced",
+                    "idp_fumbles_recovered",
+                    "idp_td",
+                ],
+            ),
         ])
     });
@@ -1,25 +1,61 @@
 use anyhow::Result;
+use rand::Rng;
+use round::round;
 use sqlx::{Error, Postgres, QueryBuilder, Transaction};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
 use time::OffsetDateTime;
 
 use crate::database::connection::get_pool;
-use crate::models::drafted_players::DraftedPlayer;
+use crate::models::draft_events::{DraftEvent, DraftEventType};
+use crate::models::drafted_players::{DraftRequest, DraftedPlayer, KeeperRequest};
+use crate::models::game_logs::{GameLog, GameLogResponse};
+use crate::models::leagues::{
+    CreateLeagueRequest, DraftOrder, DraftPick, League, LeagueResponse, LeagueTeam,
+    TradePicksRequest,
+};
+use crate::models::mock_drafts::{
+    CreateMockDraftRequest, MockCandidate, MockDraft, MockDraftPick, MockDraftResponse,
+};
 use crate::models::players::{Player, PlayerResponse, Position, Team};
-use crate::models::rankings::{Rankings, ScoringSettings};
-use crate::models::stats::Stats;
-use crate::models::users::User;
+use crate::models::projections::Projections;
+use crate::models::rankings::{LeagueFormat, RankingType, Rankings, ScoringSettings};
+use crate::models::roster::{RosterPlayer, RosterPosition, RosterResponse, RosterSettings};
+use crate::models::scoring_profiles::{ScoringProfile, ScoringRules};
+use crate::models::stats::{Stats, StatsResponse};
+use crate::models::users::{CreateUserRequest, UpdateUserRequest, User};
+use crate::services::draft_service::{self, DraftError};
+use crate::services::{draft_event_service, mock_draft_service, roster_service, scoring_service};
 
 pub mod fantasy_data_operations {
     use super::*;
 
-    pub async fn delete_old_data(tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+    const INSERT_BATCH_SIZE: usize = 500;
+
+    // Stats are only replaced for the seasons being re-scraped, so earlier
+    // seasons survive a regular refresh.
+    pub async fn delete_old_data(
+        stats_seasons: &[i32],
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<()> {
         sqlx::query!("DELETE FROM players")
             .execute(&mut **tx)
             .await?;
         sqlx::query!("DELETE FROM rankings")
             .execute(&mut **tx)
             .await?;
-        sqlx::query!("DELETE FROM stats").execute(&mut **tx).await?;
+        sqlx::query!("DELETE FROM stats WHERE season = ANY($1)", stats_seasons)
+            .execute(&mut **tx)
+            .await?;
+        sqlx::query!(
+            "DELETE FROM game_logs WHERE season = ANY($1)",
+            stats_seasons
+        )
+        .execute(&mut **tx)
+        .await?;
+        sqlx::query!("DELETE FROM projections")
+            .execute(&mut **tx)
+            .await?;
         Ok(())
     }
 
@@ -80,25 +116,71 @@ pub mod fantasy_data_operations {
             return Ok(());
         }
 
-        let mut query_builder = QueryBuilder::new(
-            "INSERT INTO rankings (
-                player_id, scoring_settings, overall, position, 
-                best, worst, average, standard_deviation
-            )",
-        );
+        // Every ranking set together runs well past Postgres' 65535 bind
+        // parameter cap, so these go in batches like the stat lines.
+        for chunk in rankings.chunks(INSERT_BATCH_SIZE) {
+            let mut query_builder = QueryBuilder::new(
+                "INSERT INTO rankings (
+                    player_id, scoring_settings, league_format, ranking_type, overall, position, 
+                    best, worst, average, standard_deviation
+                )",
+            );
 
-        query_builder.push_values(rankings, |mut b, ranking| {
-            b.push_bind(ranking.player_id)
-                .push_bind(&ranking.scoring_settings)
-                .push_bind(ranking.base.overall)
-                .push_bind(ranking.base.position)
-                .push_bind(ranking.base.best)
-                .push_bind(ranking.base.worst)
-                .push_bind(ranking.base.average)
-                .push_bind(ranking.base.standard_deviation);
-        });
+            query_builder.push_values(chunk, |mut b, ranking| {
+                b.push_bind(ranking.player_id)
+                    .push_bind(&ranking.scoring_settings)
+                    .push_bind(&ranking.league_format)
+                    .push_bind(&ranking.ranking_type)
+                    .push_bind(ranking.base.overall)
+                    .push_bind(ranking.base.position)
+                    .push_bind(ranking.base.best)
+                    .push_bind(ranking.base.worst)
+                    .push_bind(ranking.base.average)
+                    .push_bind(ranking.base.standard_deviation);
+            });
 
-        query_builder.build().execute(&mut **tx).await?;
+            query_builder.build().execute(&mut **tx).await?;
+        }
+
+        // Sheets that aren't split by format are scraped under 1QB only, and
+        // ones that aren't split by scoring under Standard only (see
+        // `RankingsScraper::get_urls`), so they're copied to the rest here.
+        sqlx::query!(
+            r#"
+            INSERT INTO rankings (
+                player_id, scoring_settings, league_format, ranking_type, overall, position,
+                best, worst, average, standard_deviation
+            )
+            SELECT
+                r.player_id, r.scoring_settings, f.league_format, r.ranking_type, r.overall,
+                r.position, r.best, r.worst, r.average, r.standard_deviation
+            FROM rankings r
+            CROSS JOIN unnest(enum_range(NULL::league_format_type)) f(league_format)
+            WHERE r.ranking_type IN ('Rookie', 'BestBall')
+                AND r.league_format = 'OneQB'
+                AND f.league_format <> r.league_format
+            "#
+        )
+        .execute(&mut **tx)
+        .await?;
+        sqlx::query!(
+            r#"
+            INSERT INTO rankings (
+                player_id, scoring_settings, league_format, ranking_type, overall, position,
+                best, worst, average, standard_deviation
+            )
+            SELECT
+                r.player_id, s.scoring_settings, r.league_format, r.ranking_type, r.overall,
+                r.position, r.best, r.worst, r.average, r.standard_deviation
+            FROM rankings r
+            CROSS JOIN unnest(enum_range(NULL::scoring_settings_type)) s(scoring_settings)
+            WHERE r.ranking_type IN ('Dynasty', 'Rookie', 'BestBall')
+                AND r.scoring_settings = 'Standard'
+                AND s.scoring_settings <> r.scoring_settings
+            "#
+        )
+        .execute(&mut **tx)
+        .await?;
         Ok(())
     }
 
@@ -110,73 +192,155 @@ pub mod fantasy_data_operations {
             return Ok(());
         }
 
-        let mut query_builder = QueryBuilder::new(
-            "INSERT INTO stats (
-                player_id, pass_cmp, pass_att, pass_cmp_pct, pass_yds, pass_yds_per_att,
-                pass_td, pass_int, pass_sacks, rush_att, rush_yds, rush_yds_per_att,
-                rush_long, rush_20, rush_td, fumbles, receptions, rec_tgt, rec_tgt_pct, rec_yds,
-                rec_yds_per_rec, rec_long, rec_20, rec_td, field_goals, fg_att,
-                fg_pct, fg_long, fg_1_19, fg_20_29, fg_30_39, fg_40_49, fg_50,
-                extra_points, xp_att, sacks, int, fumbles_recovered, fumbles_forced,
-                def_td, safeties, special_teams_td, games, standard_pts, 
-                standard_pts_per_game, half_ppr_pts, half_ppr_pts_per_game, 
-                ppr_pts, ppr_pts_per_game
-            )",
-        );
+        // Postgres caps a statement at 65535 bind parameters, which a few
+        // backfilled seasons of stat lines would exceed in a single insert.
+        for chunk in stats.chunks(INSERT_BATCH_SIZE) {
+            let mut query_builder = QueryBuilder::new(
+                "INSERT INTO stats (
+                    player_id, season, pass_cmp, pass_att, pass_cmp_pct, pass_yds, pass_yds_per_att,
+                    pass_td, pass_int, pass_sacks, rush_att, rush_yds, rush_yds_per_att,
+                    rush_long, rush_20, rush_td, fumbles, receptions, rec_tgt, rec_tgt_pct, rec_yds,
+                    rec_yds_per_rec, rec_long, rec_20, rec_td, field_goals, fg_att,
+                    fg_pct, fg_long, fg_1_19, fg_20_29, fg_30_39, fg_40_49, fg_50,
+                    extra_points, xp_att, sacks, int, fumbles_recovered, fumbles_forced,
+                    def_td, safeties, special_teams_td, points_allowed, yards_allowed,
+                    tackles, assists, tackles_for_loss, qb_hits, passes_defended, idp_sacks,
+                    idp_int, idp_fumbles_forced, idp_fumbles_recovered, idp_td, games
+                )",
+            );
 
-        query_builder.push_values(stats, |mut b, stat| {
-            b.push_bind(stat.player_id)
-                .push_bind(stat.base.pass_cmp)
-                .push_bind(stat.base.pass_att)
-                .push_bind(stat.base.pass_cmp_pct)
-                .push_bind(stat.base.pass_yds)
-                .push_bind(stat.base.pass_yds_per_att)
-                .push_bind(stat.base.pass_td)
-                .push_bind(stat.base.pass_int)
-                .push_bind(stat.base.pass_sacks)
-                .push_bind(stat.base.rush_att)
-                .push_bind(stat.base.rush_yds)
-                .push_bind(stat.base.rush_yds_per_att)
-                .push_bind(stat.base.rush_long)
-                .push_bind(stat.base.rush_20)
-                .push_bind(stat.base.rush_td)
-                .push_bind(stat.base.fumbles)
-                .push_bind(stat.base.receptions)
-                .push_bind(stat.base.rec_tgt)
-                .push_bind(stat.base.rec_tgt_pct)
-                .push_bind(stat.base.rec_yds)
-                .push_bind(stat.base.rec_yds_per_rec)
-                .push_bind(stat.base.rec_long)
-                .push_bind(stat.base.rec_20)
-                .push_bind(stat.base.rec_td)
-                .push_bind(stat.base.field_goals)
-                .push_bind(stat.base.fg_att)
-                .push_bind(stat.base.fg_pct)
-                .push_bind(stat.base.fg_long)
-                .push_bind(stat.base.fg_1_19)
-                .push_bind(stat.base.fg_20_29)
-                .push_bind(stat.base.fg_30_39)
-                .push_bind(stat.base.fg_40_49)
-                .push_bind(stat.base.fg_50)
-                .push_bind(stat.base.extra_points)
-                .push_bind(stat.base.xp_att)
-                .push_bind(stat.base.sacks)
-                .push_bind(stat.base.int)
-                .push_bind(stat.base.fumbles_recovered)
-                .push_bind(stat.base.fumbles_forced)
-                .push_bind(stat.base.def_td)
-                .push_bind(stat.base.safeties)
-                .push_bind(stat.base.special_teams_td)
-                .push_bind(stat.base.games)
-                .push_bind(stat.standard_pts)
-                .push_bind(stat.standard_pts_per_game)
-                .push_bind(stat.half_ppr_pts)
-                .push_bind(stat.half_ppr_pts_per_game)
-                .push_bind(stat.ppr_pts)
-                .push_bind(stat.ppr_pts_per_game);
-        });
+            query_builder.push_values(chunk, |mut b, stat| {
+                b.push_bind(stat.player_id)
+                    .push_bind(stat.season)
+                    .push_bind(stat.base.pass_cmp)
+                    .push_bind(stat.base.pass_att)
+                    .push_bind(stat.base.pass_cmp_pct)
+                    .push_bind(stat.base.pass_yds)
+                    .push_bind(stat.base.pass_yds_per_att)
+                    .push_bind(stat.base.pass_td)
+                    .push_bind(stat.base.pass_int)
+                    .push_bind(stat.base.pass_sacks)
+                    .push_bind(stat.base.rush_att)
+                    .push_bind(stat.base.rush_yds)
+                    .push_bind(stat.base.rush_yds_per_att)
+                    .push_bind(stat.base.rush_long)
+                    .push_bind(stat.base.rush_20)
+                    .push_bind(stat.base.rush_td)
+                    .push_bind(stat.base.fumbles)
+                    .push_bind(stat.base.receptions)
+                    .push_bind(stat.base.rec_tgt)
+                    .push_bind(stat.base.rec_tgt_pct)
+                    .push_bind(stat.base.rec_yds)
+                    .push_bind(stat.base.rec_yds_per_rec)
+                    .push_bind(stat.base.rec_long)
+                    .push_bind(stat.base.rec_20)
+                    .push_bind(stat.base.rec_td)
+                    .push_bind(stat.base.field_goals)
+                    .push_bind(stat.base.fg_att)
+                    .push_bind(stat.base.fg_pct)
+                    .push_bind(stat.base.fg_long)
+                    .push_bind(stat.base.fg_1_19)
+                    .push_bind(stat.base.fg_20_29)
+                    .push_bind(stat.base.fg_30_39)
+                    .push_bind(stat.base.fg_40_49)
+                    .push_bind(stat.base.fg_50)
+                    .push_bind(stat.base.extra_points)
+                    .push_bind(stat.base.xp_att)
+                    .push_bind(stat.base.sacks)
+                    .push_bind(stat.base.int)
+                    .push_bind(stat.base.fumbles_recovered)
+                    .push_bind(stat.base.fumbles_forced)
+                    .push_bind(stat.base.def_td)
+                    .push_bind(stat.base.safeties)
+                    .push_bind(stat.base.special_teams_td)
+                    .push_bind(stat.base.points_allowed)
+                    .push_bind(stat.base.yards_allowed)
+                    .push_bind(stat.base.tackles)
+                    .push_bind(stat.base.assists)
+                    .push_bind(stat.base.tackles_for_loss)
+                    .push_bind(stat.base.qb_hits)
+                    .push_bind(stat.base.passes_defended)
+                    .push_bind(stat.base.idp_sacks)
+                    .push_bind(stat.base.idp_int)
+                    .push_bind(stat.base.idp_fumbles_forced)
+                    .push_bind(stat.base.idp_fumbles_recovered)
+                    .push_bind(stat.base.idp_td)
+                    .push_bind(stat.base.games);
+            });
 
-        query_builder.build().execute(&mut **tx).await?;
+            query_builder.build().execute(&mut **tx).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn bulk_save_projections(
+        projections: &[Projections],
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<()> {
+        if projections.is_empty() {
+            return Ok(());
+        }
+
+        for chunk in projections.chunks(INSERT_BATCH_SIZE) {
+            let mut query_builder = QueryBuilder::new(
+                "INSERT INTO projections (
+                    player_id, pass_att, pass_cmp, pass_yds, pass_td, pass_int, rush_att,
+                    rush_yds, rush_td, receptions, rec_yds, rec_td, fumbles, field_goals,
+                    fg_att, extra_points, sacks, int, fumbles_recovered, fumbles_forced,
+                    def_td, safeties, points_allowed, yards_allowed, games
+                )",
+            );
+
+            query_builder.push_values(chunk, |mut b, projection| {
+                b.push_bind(projection.player_id)
+                    .push_bind(projection.base.pass_att)
+                    .push_bind(projection.base.pass_cmp)
+                    .push_bind(projection.base.pass_yds)
+                    .push_bind(projection.base.pass_td)
+                    .push_bind(projection.base.pass_int)
+                    .push_bind(projection.base.rush_att)
+                    .push_bind(projection.base.rush_yds)
+                    .push_bind(projection.base.rush_td)
+                    .push_bind(projection.base.receptions)
+                    .push_bind(projection.base.rec_yds)
+                    .push_bind(projection.base.rec_td)
+                    .push_bind(projection.base.fumbles)
+                    .push_bind(projection.base.field_goals)
+                    .push_bind(projection.base.fg_att)
+                    .push_bind(projection.base.extra_points)
+                    .push_bind(projection.base.sacks)
+                    .push_bind(projection.base.int)
+                    .push_bind(projection.base.fumbles_recovered)
+                    .push_bind(projection.base.fumbles_forced)
+                    .push_bind(projection.base.def_td)
+                    .push_bind(projection.base.safeties)
+                    .push_bind(projection.base.points_allowed)
+                    .push_bind(projection.base.yards_allowed)
+                    .push_bind(projection.base.games);
+            });
+
+            query_builder.build().execute(&mut **tx).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn bulk_save_game_logs(
+        game_logs: &[GameLog],
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<()> {
+        for chunk in game_logs.chunks(INSERT_BATCH_SIZE) {
+            let mut query_builder =
+                QueryBuilder::new("INSERT INTO game_logs (player_id, season, week, stats)");
+
+            query_builder.push_values(chunk, |mut b, game_log| {
+                b.push_bind(game_log.player_id)
+                    .push_bind(game_log.season)
+                    .push_bind(game_log.week)
+                    .push_bind(serde_json::to_value(&game_log.base).unwrap());
+            });
+
+            query_builder.build().execute(&mut **tx).await?;
+        }
         Ok(())
     }
 }
@@ -193,6 +357,10 @@ pub mod user_operations {
                 id,
                 username,
                 scoring_settings as "scoring_settings!: ScoringSettings",
+                league_format as "league_format!: LeagueFormat",
+                ranking_type as "ranking_type!: RankingType",
+                scoring_profile_id,
+                idp,
                 created_at
             FROM users 
             WHERE username = $1
@@ -203,20 +371,43 @@ pub mod user_operations {
         .await
     }
 
-    pub async fn create_user(
-        username: &str,
-        scoring_settings: &ScoringSettings,
-    ) -> Result<User, Error> {
+    pub async fn get_user_by_id(user_id: i32) -> Result<Option<User>, Error> {
+        let pool = get_pool()?;
+        sqlx::query_as!(
+            User,
+            r#"
+            SELECT 
+                id,
+                username,
+                scoring_settings as "scoring_settings!: ScoringSettings",
+                league_format as "league_format!: LeagueFormat",
+                ranking_type as "ranking_type!: RankingType",
+                scoring_profile_id,
+                idp,
+                created_at
+            FROM users 
+            WHERE id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn create_user(request: &CreateUserRequest) -> Result<User, Error> {
         let pool = get_pool()?;
         sqlx::query_as!(
             User,
             r#"
-            INSERT INTO users (username, scoring_settings)
-            VALUES ($1, $2)
-            RETURNING id, username, scoring_settings as "scoring_settings!: ScoringSettings", created_at
+            INSERT INTO users (username, scoring_settings, league_format, ranking_type, idp)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, username, scoring_settings as "scoring_settings!: ScoringSettings", league_format as "league_format!: LeagueFormat", ranking_type as "ranking_type!: RankingType", scoring_profile_id, idp, created_at
             "#,
-            username,
-            scoring_settings as _
+            request.username,
+            &request.scoring_settings as _,
+            &request.league_format as _,
+            &request.ranking_type as _,
+            request.idp
         )
         .fetch_one(pool)
         .await
@@ -224,18 +415,28 @@ pub mod user_operations {
 
     pub async fn update_user(
         username: &str,
-        scoring_settings: &ScoringSettings,
+        request: &UpdateUserRequest,
     ) -> Result<Option<User>, Error> {
         let pool = get_pool()?;
+        // Callers check the profile belongs to this user first; see
+        // `scoring_profile_operations::owns_scoring_profile`.
         sqlx::query_as!(
             User,
             r#"
             UPDATE users 
-            SET scoring_settings = $1 
-            WHERE username = $2
-            RETURNING id, username, scoring_settings as "scoring_settings!: ScoringSettings", created_at
+            SET scoring_settings = $1,
+                scoring_profile_id = $2,
+                league_format = COALESCE($3, league_format),
+                ranking_type = COALESCE($4, ranking_type),
+                idp = COALESCE($5, idp)
+            WHERE username = $6
+            RETURNING id, username, scoring_settings as "scoring_settings!: ScoringSettings", league_format as "league_format!: LeagueFormat", ranking_type as "ranking_type!: RankingType", scoring_profile_id, idp, created_at
             "#,
-            scoring_settings as _,
+            &request.scoring_settings as _,
+            request.scoring_profile_id,
+            &request.league_format as _,
+            &request.ranking_type as _,
+            request.idp,
             username
         )
         .fetch_optional(pool)
@@ -243,10 +444,230 @@ pub mod user_operations {
     }
 }
 
+pub mod scoring_profile_operations {
+    use super::*;
+
+    // Stored rules that no longer deserialize (e.g. written by an older
+    // version) surface as a decode error instead of a panic.
+    fn decode_rules(rules: serde_json::Value) -> Result<ScoringRules, Error> {
+        ScoringRules::try_from(rules).map_err(|e| Error::Decode(Box::new(e)))
+    }
+
+    // Whether `profile_id` is one of the user's own profiles.
+    pub async fn owns_scoring_profile(username: &str, profile_id: i32) -> Result<bool, Error> {
+        let pool = get_pool()?;
+        let row = sqlx::query!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM scoring_profiles sp
+                JOIN users u ON u.id = sp.user_id
+                WHERE sp.id = $1 AND u.username = $2
+            ) as "owned!"
+            "#,
+            profile_id,
+            username
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row.owned)
+    }
+
+    pub async fn get_scoring_profiles(user_id: i32) -> Result<Vec<ScoringProfile>, Error> {
+        let pool = get_pool()?;
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, user_id, name, rules as "rules!: serde_json::Value", created_at
+            FROM scoring_profiles
+            WHERE user_id = $1
+            ORDER BY name
+            "#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(ScoringProfile {
+                    id: row.id,
+                    user_id: row.user_id,
+                    name: row.name,
+                    rules: decode_rules(row.rules)?,
+                    created_at: row.created_at,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn get_scoring_profile(
+        user_id: i32,
+        profile_id: i32,
+    ) -> Result<Option<ScoringProfile>, Error> {
+        let pool = get_pool()?;
+        let row = sqlx::query!(
+            r#"
+            SELECT id, user_id, name, rules as "rules!: serde_json::Value", created_at
+            FROM scoring_profiles
+            WHERE id = $1 AND user_id = $2
+            "#,
+            profile_id,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        row.map(|row| {
+            Ok(ScoringProfile {
+                id: row.id,
+                user_id: row.user_id,
+                name: row.name,
+                rules: decode_rules(row.rules)?,
+                created_at: row.created_at,
+            })
+        })
+        .transpose()
+    }
+
+    pub async fn create_scoring_profile(
+        user_id: i32,
+        name: &str,
+        rules: &ScoringRules,
+    ) -> Result<ScoringProfile, Error> {
+        let pool = get_pool()?;
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO scoring_profiles (user_id, name, rules)
+            VALUES ($1, $2, $3)
+            RETURNING id, user_id, name, rules as "rules!: serde_json::Value", created_at
+            "#,
+            user_id,
+            name,
+            serde_json::to_value(rules).unwrap()
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(ScoringProfile {
+            id: row.id,
+            user_id: row.user_id,
+            name: row.name,
+            rules: decode_rules(row.rules)?,
+            created_at: row.created_at,
+        })
+    }
+
+    pub async fn update_scoring_profile(
+        user_id: i32,
+        profile_id: i32,
+        name: &str,
+        rules: &ScoringRules,
+    ) -> Result<Option<ScoringProfile>, Error> {
+        let pool = get_pool()?;
+        let row = sqlx::query!(
+            r#"
+            UPDATE scoring_profiles
+            SET name = $1, rules = $2
+            WHERE id = $3 AND user_id = $4
+            RETURNING id, user_id, name, rules as "rules!: serde_json::Value", created_at
+            "#,
+            name,
+            serde_json::to_value(rules).unwrap(),
+            profile_id,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        row.map(|row| {
+            Ok(ScoringProfile {
+                id: row.id,
+                user_id: row.user_id,
+                name: row.name,
+                rules: decode_rules(row.rules)?,
+                created_at: row.created_at,
+            })
+        })
+        .transpose()
+    }
+
+    pub async fn delete_scoring_profile(user_id: i32, profile_id: i32) -> Result<bool, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM scoring_profiles
+            WHERE id = $1 AND user_id = $2
+            "#,
+            profile_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET scoring_profile_id = NULL
+            WHERE id = $1 AND scoring_profile_id = $2
+            "#,
+            user_id,
+            profile_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // Same as `get_active_scoring_rules`, for a user that's already loaded.
+    pub async fn get_scoring_rules(user: &User) -> Result<ScoringRules, Error> {
+        let profile = match user.scoring_profile_id {
+            Some(profile_id) => get_scoring_profile(user.id, profile_id).await?,
+            None => None,
+        };
+        Ok(match profile {
+            Some(profile) => profile.rules,
+            None => scoring_service::preset(&user.scoring_settings),
+        })
+    }
+
+    // The user's active custom profile, or the preset for their scoring
+    // settings when none is selected. None if the user doesn't exist.
+    pub async fn get_active_scoring_rules(user_id: i32) -> Result<Option<ScoringRules>, Error> {
+        let pool = get_pool()?;
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                u.scoring_settings as "scoring_settings!: ScoringSettings",
+                sp.rules as "rules?: serde_json::Value"
+            FROM users u
+            LEFT JOIN scoring_profiles sp ON sp.id = u.scoring_profile_id
+            WHERE u.id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        row.map(|row| match row.rules {
+            Some(rules) => decode_rules(rules),
+            None => Ok(scoring_service::preset(&row.scoring_settings)),
+        })
+        .transpose()
+    }
+}
+
 pub mod player_operations {
     use super::*;
 
-    pub async fn get_players(user_id: i32) -> Result<Vec<PlayerResponse>, Error> {
+    // The user's ranking set, best first, with unscored stats and
+    // projections. See `board_service::build_board` for the full board.
+    pub async fn get_players(
+        user_id: i32,
+        season: Option<i32>,
+    ) -> Result<Vec<PlayerResponse>, Error> {
         let pool = get_pool()?;
         sqlx::query_as!(
             PlayerResponse,
@@ -269,124 +690,1512 @@ pub mod player_operations {
                     'average', r.average,
                     'standard_deviation', r.standard_deviation
                 ) as "rankings!: serde_json::Value",
-                jsonb_build_object(
-                    'pass_cmp', COALESCE(s.pass_cmp, 0),
-                    'pass_att', COALESCE(s.pass_att, 0),
-                    'pass_cmp_pct', COALESCE(s.pass_cmp_pct, 0),
-                    'pass_yds', COALESCE(s.pass_yds, 0),
-                    'pass_yds_per_att', COALESCE(s.pass_yds_per_att, 0),
-                    'pass_td', COALESCE(s.pass_td, 0),
-                    'pass_int', COALESCE(s.pass_int, 0),
-                    'pass_sacks', COALESCE(s.pass_sacks, 0),
-                    'rush_att', COALESCE(s.rush_att, 0),
-                    'rush_yds', COALESCE(s.rush_yds, 0),
-                    'rush_yds_per_att', COALESCE(s.rush_yds_per_att, 0),
-                    'rush_long', COALESCE(s.rush_long, 0),
-                    'rush_20', COALESCE(s.rush_20, 0),
-                    'rush_td', COALESCE(s.rush_td, 0),
-                    'fumbles', COALESCE(s.fumbles, 0),
-                    'receptions', COALESCE(s.receptions, 0),
-                    'rec_tgt', COALESCE(s.rec_tgt, 0),
-                    'rec_tgt_pct', COALESCE(s.rec_tgt_pct, 0),
-                    'rec_yds', COALESCE(s.rec_yds, 0),
-                    'rec_yds_per_rec', COALESCE(s.rec_yds_per_rec, 0),
-                    'rec_long', COALESCE(s.rec_long, 0),
-                    'rec_20', COALESCE(s.rec_20, 0),
-                    'rec_td', COALESCE(s.rec_td, 0),
-                    'field_goals', COALESCE(s.field_goals, 0),
-                    'fg_att', COALESCE(s.fg_att, 0),
-                    'fg_pct', COALESCE(s.fg_pct, 0),
-                    'fg_long', COALESCE(s.fg_long, 0),
-                    'fg_1_19', COALESCE(s.fg_1_19, 0),
-                    'fg_20_29', COALESCE(s.fg_20_29, 0),
-                    'fg_30_39', COALESCE(s.fg_30_39, 0),
-                    'fg_40_49', COALESCE(s.fg_40_49, 0),
-                    'fg_50', COALESCE(s.fg_50, 0),
-                    'extra_points', COALESCE(s.extra_points, 0),
-                    'xp_att', COALESCE(s.xp_att, 0),
-                    'sacks', COALESCE(s.sacks, 0),
-                    'int', COALESCE(s.int, 0),
-                    'fumbles_recovered', COALESCE(s.fumbles_recovered, 0),
-                    'fumbles_forced', COALESCE(s.fumbles_forced, 0),
-                    'def_td', COALESCE(s.def_td, 0),
-                    'safeties', COALESCE(s.safeties, 0),
-                    'special_teams_td', COALESCE(s.special_teams_td, 0),
-                    'games', COALESCE(s.games, 0),
-                    'points', CASE u.scoring_settings
-                        WHEN 'Standard' THEN s.standard_pts
-                        WHEN 'Half' THEN s.half_ppr_pts
-                        WHEN 'PPR' THEN s.ppr_pts
-                    END,
-                    'points_per_game', CASE u.scoring_settings
-                        WHEN 'Standard' THEN s.standard_pts_per_game
-                        WHEN 'Half' THEN s.half_ppr_pts_per_game
-                        WHEN 'PPR' THEN s.ppr_pts_per_game
-                    END
-                ) as "stats!: serde_json::Value",
-                d.player_id IS NOT NULL as "drafted!: bool"
+                -- Missing stats (and NULL columns) fall back to zero via StatsBase defaults.
+                COALESCE(jsonb_strip_nulls(to_jsonb(s)), '{}'::jsonb) as "stats!: serde_json::Value",
+                COALESCE(jsonb_strip_nulls(to_jsonb(pr)), '{}'::jsonb) as "projections!: serde_json::Value",
+                d.player_id IS NOT NULL as "drafted!: bool",
+                COALESCE(d.drafted_by_me, FALSE) as "drafted_by_me!: bool",
+                d.keeper_round as "keeper_round?",
+                -- Filled in by `board_service::build_board`.
+                NULL::INTEGER as "keeper_value?: i32",
+                NULL::INTEGER as "auction_value?: i32",
+                NULL::DOUBLE PRECISION as "vorp?: f64",
+                NULL::INTEGER as "vbd_rank?: i32"
             FROM players p
             INNER JOIN users u ON u.id = $1
             INNER JOIN rankings r ON p.id = r.player_id 
                 AND r.scoring_settings = u.scoring_settings
+                AND r.league_format = u.league_format
+                AND r.ranking_type = u.ranking_type
             LEFT JOIN stats s ON p.id = s.player_id
+                AND s.season = COALESCE($2, (SELECT MAX(season) FROM stats))
+            LEFT JOIN projections pr ON p.id = pr.player_id
             LEFT JOIN drafted_players d ON d.user_id = $1
                 AND p.id = d.player_id
+            WHERE u.idp OR p.position NOT IN ('DL', 'LB', 'DB')
             ORDER BY r.overall ASC
             "#,
             user_id,
+            season,
         )
         .fetch_all(pool)
         .await
     }
-}
-
-pub mod drafted_player_operations {
-    use super::*;
 
-    pub async fn draft_player(user_id: i32, player_id: i32) -> Result<DraftedPlayer, Error> {
+    // The undrafted player with the best overall rank in the user's ranking
+    // set.
+    pub async fn get_best_available(user_id: i32) -> Result<Option<i32>, Error> {
         let pool = get_pool()?;
-        sqlx::query_as!(
-            DraftedPlayer,
+        sqlx::query_scalar!(
             r#"
-            INSERT INTO drafted_players (user_id, player_id)
-            VALUES ($1, $2)
-            RETURNING id, user_id, player_id, drafted_at
+            SELECT p.id
+            FROM players p
+            INNER JOIN users u ON u.id = $1
+            INNER JOIN rankings r ON p.id = r.player_id
+                AND r.scoring_settings = u.scoring_settings
+                AND r.league_format = u.league_format
+                AND r.ranking_type = u.ranking_type
+            WHERE (u.idp OR p.position NOT IN ('DL', 'LB', 'DB'))
+                AND NOT EXISTS (
+                    SELECT 1 FROM drafted_players d
+                    WHERE d.user_id = $1 AND d.player_id = p.id
+                )
+            ORDER BY r.overall ASC
+            LIMIT 1
             "#,
-            user_id,
-            player_id
+            user_id
         )
-        .fetch_one(pool)
+        .fetch_optional(pool)
         .await
     }
 
-    pub async fn undraft_player(user_id: i32, player_id: i32) -> Result<bool, Error> {
+    // Weekly lines per player for the same season the board's stats come
+    // from, in week order.
+    pub async fn get_weekly_stats(
+        season: Option<i32>,
+    ) -> Result<HashMap<i32, Vec<StatsResponse>>, Error> {
         let pool = get_pool()?;
-        let result = sqlx::query!(
+        let rows = sqlx::query!(
             r#"
-            DELETE FROM drafted_players
-            WHERE user_id = $1 AND player_id = $2
+            SELECT g.player_id, g.stats
+            FROM game_logs g
+            WHERE g.season = COALESCE($1, (SELECT MAX(season) FROM stats))
+            ORDER BY g.player_id, g.week
+            "#,
+            season
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut weekly_stats: HashMap<i32, Vec<StatsResponse>> = HashMap::new();
+        for row in rows {
+            weekly_stats
+                .entry(row.player_id)
+                .or_default()
+                .push(StatsResponse::from(row.stats));
+        }
+
+        Ok(weekly_stats)
+    }
+
+    // Every stored season for one player, newest first, scored with the
+    // user's active rules.
+    pub async fn get_player_stats(
+        user_id: i32,
+        player_id: i32,
+    ) -> Result<Vec<StatsResponse>, Error> {
+        let pool = get_pool()?;
+        let rules = match scoring_profile_operations::get_active_scoring_rules(user_id).await? {
+            Some(rules) => rules,
+            None => return Ok(Vec::new()),
+        };
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                p.position as "position!: Position",
+                jsonb_strip_nulls(to_jsonb(s)) as "stats!: serde_json::Value"
+            FROM stats s
+            INNER JOIN players p ON p.id = s.player_id
+            WHERE s.player_id = $1
+            ORDER BY s.season DESC
             "#,
-            user_id,
             player_id
         )
-        .execute(pool)
+        .fetch_all(pool)
         .await?;
 
-        Ok(result.rows_affected() > 0)
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let mut stats = StatsResponse::from(row.stats);
+                scoring_service::apply_scoring(&mut stats, &row.position, &rules);
+                stats
+            })
+            .collect())
     }
 
-    pub async fn undraft_all(user_id: i32) -> Result<u64, Error> {
+    pub async fn get_player_game_logs(
+        user_id: i32,
+        player_id: i32,
+        season: Option<i32>,
+    ) -> Result<Vec<GameLogResponse>, Error> {
         let pool = get_pool()?;
-        let result = sqlx::query!(
+        let rules = match scoring_profile_operations::get_active_scoring_rules(user_id).await? {
+            Some(rules) => rules,
+            None => return Ok(Vec::new()),
+        };
+
+        let rows = sqlx::query!(
             r#"
-            DELETE FROM drafted_players
+            SELECT
+                g.season,
+                g.week,
+                p.position as "position!: Position",
+                g.stats
+            FROM game_logs g
+            INNER JOIN players p ON p.id = g.player_id
+            WHERE g.player_id = $1
+                AND g.season = COALESCE(
+                    $2,
+                    (SELECT MAX(season) FROM game_logs WHERE player_id = $1)
+                )
+            ORDER BY g.week
+            "#,
+            player_id,
+            season
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let mut stats = StatsResponse::from(row.stats);
+                stats.season = Some(row.season);
+                scoring_service::apply_scoring(&mut stats, &row.position, &rules);
+                GameLogResponse {
+                    week: row.week,
+                    stats,
+                }
+            })
+            .collect())
+    }
+}
+
+pub mod league_operations {
+    use super::*;
+
+    pub(crate) async fn get_league_row(
+        user_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Option<League>, Error> {
+        sqlx::query_as!(
+            League,
+            r#"
+            SELECT
+                id,
+                user_id,
+                name,
+                draft_order as "draft_order!: DraftOrder",
+                rounds,
+                current_pick,
+                auction_budget,
+                pick_seconds,
+                pick_deadline,
+                created_at
+            FROM leagues
             WHERE user_id = $1
+            FOR UPDATE
             "#,
             user_id
         )
-        .execute(pool)
+        .fetch_optional(&mut **tx)
+        .await
+    }
+
+    pub(crate) async fn get_league_teams(
+        league_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<LeagueTeam>, Error> {
+        sqlx::query_as!(
+            LeagueTeam,
+            r#"
+            SELECT id, league_id, name, draft_position
+            FROM league_teams
+            WHERE league_id = $1
+            ORDER BY draft_position
+            "#,
+            league_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    pub(crate) async fn get_draft_picks(
+        league_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<DraftPick>, Error> {
+        sqlx::query_as!(
+            DraftPick,
+            r#"
+            SELECT league_id, overall_pick, round, original_team_id, team_id
+            FROM draft_picks
+            WHERE league_id = $1
+            ORDER BY overall_pick
+            "#,
+            league_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    // (team id, price) of every pick made for a league team.
+    pub(crate) async fn get_team_picks(
+        user_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<(i32, Option<i32>)>, Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT team_id as "team_id!", price
+            FROM drafted_players
+            WHERE user_id = $1 AND team_id IS NOT NULL
+            "#,
+            user_id
+        )
+        .fetch_all(&mut **tx)
         .await?;
 
-        Ok(result.rows_affected())
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.team_id, row.price))
+            .collect())
+    }
+
+    // Overall picks already used, including keepers slotted ahead of the clock.
+    pub(crate) async fn get_filled_picks(
+        user_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<i32>, Error> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT overall_pick as "overall_pick!"
+            FROM drafted_players
+            WHERE user_id = $1 AND overall_pick IS NOT NULL
+            "#,
+            user_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    // Points the clock at the pick after the latest one still on the board,
+    // so removing picks hands the clock back. Keepers don't move the clock.
+    // A running pick clock restarts for whoever is now up.
+    pub(crate) async fn rewind_draft_clock(
+        user_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), Error> {
+        sqlx::query!(
+            r#"
+            UPDATE leagues
+            SET current_pick = COALESCE(
+                (
+                    SELECT MAX(overall_pick) FROM drafted_players
+                    WHERE user_id = $1 AND NOT is_keeper
+                ),
+                0
+            ) + 1,
+            pick_deadline = NOW() + pick_seconds * INTERVAL '1 second'
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_league(user_id: i32) -> Result<Option<LeagueResponse>, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let league = match get_league_row(user_id, &mut tx).await? {
+            Some(league) => league,
+            None => return Ok(None),
+        };
+        let teams = get_league_teams(league.id, &mut tx).await?;
+        let draft_picks = get_draft_picks(league.id, &mut tx).await?;
+        let picks = get_team_picks(user_id, &mut tx).await?;
+        let filled_picks = get_filled_picks(user_id, &mut tx).await?;
+        tx.commit().await?;
+
+        let on_the_clock =
+            draft_service::on_the_clock(&league, &teams, &draft_picks, &filled_picks);
+        let budgets = league
+            .auction_budget
+            .map(|budget| draft_service::team_budgets(&league, budget, &teams, &picks));
+        let pick_seconds_remaining = league.pick_deadline.map(|pick_deadline| {
+            (pick_deadline - OffsetDateTime::now_utc())
+                .whole_seconds()
+                .max(0)
+        });
+        Ok(Some(LeagueResponse {
+            league,
+            teams,
+            picks: draft_picks,
+            on_the_clock,
+            budgets,
+            pick_seconds_remaining,
+        }))
+    }
+
+    // Replaces the user's league, as long as none of the old league's picks
+    // are still on the board.
+    pub async fn create_league(
+        user_id: i32,
+        request: &CreateLeagueRequest,
+    ) -> Result<LeagueResponse, DraftError> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        delete_league_rows(user_id, &mut tx).await?;
+
+        let league = sqlx::query_as!(
+            League,
+            r#"
+            INSERT INTO leagues (user_id, name, draft_order, rounds, auction_budget)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING
+                id,
+                user_id,
+                name,
+                draft_order as "draft_order!: DraftOrder",
+                rounds,
+                current_pick,
+                auction_budget,
+                pick_seconds,
+                pick_deadline,
+                created_at
+            "#,
+            user_id,
+            request.name,
+            &request.draft_order as _,
+            request.rounds,
+            request.auction_budget
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let draft_positions: Vec<i32> = (1..=request.teams.len() as i32).collect();
+        let mut teams = sqlx::query_as!(
+            LeagueTeam,
+            r#"
+            INSERT INTO league_teams (league_id, name, draft_position)
+            SELECT $1, name, draft_position
+            FROM UNNEST($2::TEXT[], $3::INTEGER[]) AS t(name, draft_position)
+            RETURNING id, league_id, name, draft_position
+            "#,
+            league.id,
+            &request.teams,
+            &draft_positions
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        teams.sort_by_key(|team| team.draft_position);
+
+        // Auctions have no pick order to own.
+        let picks = match league.auction_budget {
+            Some(_) => Vec::new(),
+            None => draft_service::draft_picks(&league, &teams),
+        };
+        let overall_picks: Vec<i32> = picks.iter().map(|pick| pick.overall_pick).collect();
+        let rounds: Vec<i32> = picks.iter().map(|pick| pick.round).collect();
+        let team_ids: Vec<i32> = picks.iter().map(|pick| pick.team_id).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO draft_picks (league_id, overall_pick, round, original_team_id, team_id)
+            SELECT $1, overall_pick, round, team_id, team_id
+            FROM UNNEST($2::INTEGER[], $3::INTEGER[], $4::INTEGER[])
+                AS t(overall_pick, round, team_id)
+            "#,
+            league.id,
+            &overall_picks,
+            &rounds,
+            &team_ids
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        let on_the_clock = draft_service::on_the_clock(&league, &teams, &picks, &[]);
+        let budgets = league
+            .auction_budget
+            .map(|budget| draft_service::team_budgets(&league, budget, &teams, &[]));
+        Ok(LeagueResponse {
+            league,
+            teams,
+            picks,
+            on_the_clock,
+            budgets,
+            pick_seconds_remaining: None,
+        })
+    }
+
+    // Moves pick ownership between two teams in one transaction. Returns
+    // Ok(None) when the user has no league.
+    pub async fn trade_picks(
+        user_id: i32,
+        request: &TradePicksRequest,
+    ) -> Result<Option<Vec<DraftPick>>, DraftError> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let league = match get_league_row(user_id, &mut tx).await? {
+            Some(league) => league,
+            None => return Ok(None),
+        };
+        if league.auction_budget.is_some() {
+            return Err(DraftError::NoPickSlots);
+        }
+
+        let teams = get_league_teams(league.id, &mut tx).await?;
+        let picks = get_draft_picks(league.id, &mut tx).await?;
+        let filled_picks = get_filled_picks(user_id, &mut tx).await?;
+        draft_service::validate_trade(&teams, &picks, &filled_picks, request)?;
+
+        for (team_id, overall_picks) in [
+            (request.other_team_id, &request.sends),
+            (request.team_id, &request.receives),
+        ] {
+            sqlx::query!(
+                r#"
+                UPDATE draft_picks
+                SET team_id = $1
+                WHERE league_id = $2 AND overall_pick = ANY($3)
+                "#,
+                team_id,
+                league.id,
+                overall_picks
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        let picks = get_draft_picks(league.id, &mut tx).await?;
+        tx.commit().await?;
+
+        Ok(Some(picks))
+    }
+
+    // Starts (or restarts) the clock for the pick that's up, or stops it.
+    // Returns false when the user has no league.
+    pub async fn set_pick_clock(user_id: i32, pick_seconds: Option<i32>) -> Result<bool, Error> {
+        let pool = get_pool()?;
+        let result = sqlx::query!(
+            r#"
+            UPDATE leagues
+            SET pick_seconds = $1::INTEGER,
+                pick_deadline = NOW() + $1::INTEGER * INTERVAL '1 second'
+            WHERE user_id = $2
+            "#,
+            pick_seconds,
+            user_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // Boards whose pick clock has run out. Auctions have no single team to
+    // pick for, so they're never autopicked.
+    pub async fn get_expired_pick_clocks() -> Result<Vec<i32>, Error> {
+        let pool = get_pool()?;
+        sqlx::query_scalar!(
+            r#"
+            SELECT user_id
+            FROM leagues
+            WHERE pick_deadline <= NOW() AND auction_budget IS NULL
+            "#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn delete_league(user_id: i32) -> Result<bool, DraftError> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let deleted = delete_league_rows(user_id, &mut tx).await?;
+        tx.commit().await?;
+
+        Ok(deleted)
+    }
+
+    async fn delete_league_rows(
+        user_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<bool, DraftError> {
+        // Dropping the teams would orphan their picks without a draft event
+        // to undo, so the picks have to be cleared first.
+        let has_picks = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM drafted_players
+                WHERE user_id = $1 AND team_id IS NOT NULL
+            ) as "exists!"
+            "#,
+            user_id
+        )
+        .fetch_one(&mut **tx)
+        .await?;
+        if has_picks {
+            return Err(DraftError::LeagueHasPicks);
+        }
+
+        sqlx::query!(
+            r#"
+            DELETE FROM draft_picks
+            WHERE league_id IN (SELECT id FROM leagues WHERE user_id = $1)
+            "#,
+            user_id
+        )
+        .execute(&mut **tx)
+        .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM league_teams
+            WHERE league_id IN (SELECT id FROM leagues WHERE user_id = $1)
+            "#,
+            user_id
+        )
+        .execute(&mut **tx)
+        .await?;
+        let result = sqlx::query!("DELETE FROM leagues WHERE user_id = $1", user_id)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+pub mod roster_settings_operations {
+    use super::*;
+
+    pub async fn get_roster_settings(user_id: i32) -> Result<RosterSettings, Error> {
+        let pool = get_pool()?;
+        let roster_settings = sqlx::query_as!(
+            RosterSettings,
+            r#"
+            SELECT qb, rb, wr, te, flex, superflex, k, dst, dl, lb, db, bench
+            FROM roster_settings
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(roster_settings.unwrap_or_default())
+    }
+
+    pub async fn update_roster_settings(
+        user_id: i32,
+        roster_settings: &RosterSettings,
+    ) -> Result<RosterSettings, Error> {
+        let pool = get_pool()?;
+        sqlx::query_as!(
+            RosterSettings,
+            r#"
+            INSERT INTO roster_settings (
+                user_id, qb, rb, wr, te, flex, superflex, k, dst, dl, lb, db, bench
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (user_id) DO UPDATE SET
+                qb = EXCLUDED.qb,
+                rb = EXCLUDED.rb,
+                wr = EXCLUDED.wr,
+                te = EXCLUDED.te,
+                flex = EXCLUDED.flex,
+                superflex = EXCLUDED.superflex,
+                k = EXCLUDED.k,
+                dst = EXCLUDED.dst,
+                dl = EXCLUDED.dl,
+                lb = EXCLUDED.lb,
+                db = EXCLUDED.db,
+                bench = EXCLUDED.bench
+            RETURNING qb, rb, wr, te, flex, superflex, k, dst, dl, lb, db, bench
+            "#,
+            user_id,
+            roster_settings.qb,
+            roster_settings.rb,
+            roster_settings.wr,
+            roster_settings.te,
+            roster_settings.flex,
+            roster_settings.superflex,
+            roster_settings.k,
+            roster_settings.dst,
+            roster_settings.dl,
+            roster_settings.lb,
+            roster_settings.db,
+            roster_settings.bench
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
+
+pub mod drafted_player_operations {
+    use super::*;
+
+    // A player can only be on the board once per user, so a second pick of
+    // the same player trips the unique constraint.
+    fn unavailable_if_taken(e: Error) -> DraftError {
+        match &e {
+            Error::Database(db_error) if db_error.is_unique_violation() => {
+                DraftError::PlayerUnavailable
+            }
+            _ => DraftError::from(e),
+        }
+    }
+
+    // Without a league this just crosses the player off. In a league draft
+    // the pick is recorded against the team on the clock (or the winning
+    // bidder in an auction), and the clock moves on.
+    pub async fn draft_player(
+        user_id: i32,
+        player_id: i32,
+        request: &DraftRequest,
+    ) -> Result<DraftedPlayer, DraftError> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+
+        let slot = match league_operations::get_league_row(user_id, &mut tx).await? {
+            Some(league) => {
+                let teams = league_operations::get_league_teams(league.id, &mut tx).await?;
+                let draft_picks = league_operations::get_draft_picks(league.id, &mut tx).await?;
+                let filled_picks = league_operations::get_filled_picks(user_id, &mut tx).await?;
+                let mut slot =
+                    draft_service::on_the_clock(&league, &teams, &draft_picks, &filled_picks)
+                        .ok_or(DraftError::DraftComplete)?;
+                if request
+                    .overall_pick
+                    .is_some_and(|overall_pick| overall_pick != slot.overall_pick)
+                {
+                    return Err(DraftError::StalePick);
+                }
+                match league.auction_budget {
+                    Some(budget) => {
+                        let (Some(team_id), Some(price)) = (request.team_id, request.price) else {
+                            return Err(DraftError::MissingWinningBid);
+                        };
+                        let picks = league_operations::get_team_picks(user_id, &mut tx).await?;
+                        let budgets = draft_service::team_budgets(&league, budget, &teams, &picks);
+                        draft_service::validate_bid(&budgets, team_id, price)?;
+                        slot.team_id = team_id;
+                    }
+                    None => {
+                        if request
+                            .team_id
+                            .is_some_and(|team_id| team_id != slot.team_id)
+                        {
+                            return Err(DraftError::OutOfTurn {
+                                on_the_clock: slot.team_id,
+                            });
+                        }
+                    }
+                }
+                Some((slot, league.auction_budget.and(request.price)))
+            }
+            None => None,
+        };
+
+        let drafted_player = sqlx::query_as!(
+            DraftedPlayer,
+            r#"
+            INSERT INTO drafted_players (
+                user_id, player_id, team_id, round, overall_pick, drafted_by_me, price
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING
+                id, user_id, player_id, team_id, round, overall_pick, drafted_by_me, price,
+                is_keeper, keeper_round, drafted_at
+            "#,
+            user_id,
+            player_id,
+            slot.as_ref().map(|(slot, _)| slot.team_id),
+            slot.as_ref().map(|(slot, _)| slot.round),
+            slot.as_ref().map(|(slot, _)| slot.overall_pick),
+            request.drafted_by_me,
+            slot.as_ref().and_then(|(_, price)| *price)
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(unavailable_if_taken)?;
+
+        if let Some((slot, _)) = &slot {
+            sqlx::query!(
+                r#"
+                UPDATE leagues
+                SET current_pick = $1 + 1,
+                    pick_deadline = NOW() + pick_seconds * INTERVAL '1 second'
+                WHERE user_id = $2
+                "#,
+                slot.overall_pick,
+                user_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        draft_event_operations::record_event(
+            user_id,
+            DraftEventType::Draft,
+            None,
+            std::slice::from_ref(&drafted_player),
+            &mut tx,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(drafted_player)
+    }
+
+    // Without a league the keeper is just crossed off and tagged. In a snake
+    // or linear league it takes the team's pick in the keeper round; in an
+    // auction it takes one of the team's roster spots.
+    pub async fn keep_player(
+        user_id: i32,
+        player_id: i32,
+        request: &KeeperRequest,
+    ) -> Result<DraftedPlayer, DraftError> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+
+        let (team_id, overall_pick) =
+            match league_operations::get_league_row(user_id, &mut tx).await? {
+                Some(league) => {
+                    let team_id = request.team_id.ok_or(DraftError::MissingKeeperTeam)?;
+                    let teams = league_operations::get_league_teams(league.id, &mut tx).await?;
+                    if !teams.iter().any(|team| team.id == team_id) {
+                        return Err(DraftError::UnknownTeam);
+                    }
+                    if request.keeper_round < 1 || request.keeper_round > league.rounds {
+                        return Err(DraftError::InvalidKeeperRound);
+                    }
+
+                    let overall_pick = match league.auction_budget {
+                        Some(budget) => {
+                            let team_picks =
+                                league_operations::get_team_picks(user_id, &mut tx).await?;
+                            let budgets =
+                                draft_service::team_budgets(&league, budget, &teams, &team_picks);
+                            draft_service::validate_open_slot(&budgets, team_id)?;
+                            None
+                        }
+                        None => {
+                            let draft_picks =
+                                league_operations::get_draft_picks(league.id, &mut tx).await?;
+                            let owned_picks = draft_service::team_picks_in_round(
+                                &league.draft_order,
+                                &teams,
+                                &draft_picks,
+                                team_id,
+                                request.keeper_round,
+                            );
+                            if owned_picks.is_empty() {
+                                return Err(DraftError::NoPickInRound);
+                            }
+                            let filled_picks =
+                                league_operations::get_filled_picks(user_id, &mut tx).await?;
+                            let overall_pick = owned_picks
+                                .into_iter()
+                                .find(|overall_pick| !filled_picks.contains(overall_pick))
+                                .ok_or(DraftError::PickTaken)?;
+                            Some(overall_pick)
+                        }
+                    };
+                    (Some(team_id), overall_pick)
+                }
+                None if request.keeper_round < 1 => {
+                    return Err(DraftError::InvalidKeeperRound);
+                }
+                None => (None, None),
+            };
+
+        let drafted_player = sqlx::query_as!(
+            DraftedPlayer,
+            r#"
+            INSERT INTO drafted_players (
+                user_id, player_id, team_id, round, overall_pick, drafted_by_me,
+                is_keeper, keeper_round
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, TRUE, $4)
+            RETURNING
+                id, user_id, player_id, team_id, round, overall_pick, drafted_by_me, price,
+                is_keeper, keeper_round, drafted_at
+            "#,
+            user_id,
+            player_id,
+            team_id,
+            request.keeper_round,
+            overall_pick,
+            request.drafted_by_me
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(unavailable_if_taken)?;
+        draft_event_operations::record_event(
+            user_id,
+            DraftEventType::Draft,
+            None,
+            std::slice::from_ref(&drafted_player),
+            &mut tx,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(drafted_player)
+    }
+
+    pub async fn undraft_player(user_id: i32, player_id: i32) -> Result<bool, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let removed = sqlx::query_as!(
+            DraftedPlayer,
+            r#"
+            DELETE FROM drafted_players
+            WHERE user_id = $1 AND player_id = $2
+            RETURNING
+                id, user_id, player_id, team_id, round, overall_pick, drafted_by_me, price,
+                is_keeper, keeper_round, drafted_at
+            "#,
+            user_id,
+            player_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        if !removed.is_empty() {
+            league_operations::rewind_draft_clock(user_id, &mut tx).await?;
+            draft_event_operations::record_event(
+                user_id,
+                DraftEventType::Undraft,
+                None,
+                &removed,
+                &mut tx,
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(!removed.is_empty())
+    }
+
+    pub async fn undraft_all(user_id: i32) -> Result<u64, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let removed = sqlx::query_as!(
+            DraftedPlayer,
+            r#"
+            DELETE FROM drafted_players
+            WHERE user_id = $1
+            RETURNING
+                id, user_id, player_id, team_id, round, overall_pick, drafted_by_me, price,
+                is_keeper, keeper_round, drafted_at
+            "#,
+            user_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        if !removed.is_empty() {
+            league_operations::rewind_draft_clock(user_id, &mut tx).await?;
+            draft_event_operations::record_event(
+                user_id,
+                DraftEventType::Reset,
+                None,
+                &removed,
+                &mut tx,
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(removed.len() as u64)
+    }
+
+    // A warning for a player I just drafted who doesn't fit any open slot on
+    // my roster.
+    pub async fn roster_warning(user_id: i32, player_id: i32) -> Result<Option<String>, Error> {
+        let roster_settings = roster_settings_operations::get_roster_settings(user_id).await?;
+        let roster = get_roster(user_id).await?;
+        let players: Vec<RosterPlayer> = roster
+            .positions
+            .into_iter()
+            .flat_map(|group| group.players)
+            .collect();
+        if roster_service::fits_open_slot(&roster_settings, &players, player_id) {
+            return Ok(None);
+        }
+
+        Ok(players
+            .iter()
+            .find(|player| player.id == player_id)
+            .map(|player| {
+                format!(
+                    "No open roster slot for {} ({})",
+                    player.name, player.position
+                )
+            }))
+    }
+
+    // My drafted players grouped by position and slotted into the board's
+    // roster, with projections scored by the user's active rules.
+    pub async fn get_roster(user_id: i32) -> Result<RosterResponse, Error> {
+        let pool = get_pool()?;
+        let roster_settings = roster_settings_operations::get_roster_settings(user_id).await?;
+        let rules = scoring_profile_operations::get_active_scoring_rules(user_id)
+            .await?
+            .unwrap_or_default();
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                p.id,
+                p.name,
+                p.position as "position!: Position",
+                p.team as "team!: Team",
+                p.bye_week,
+                COALESCE(jsonb_strip_nulls(to_jsonb(pr)), '{}'::jsonb) as "projections!: serde_json::Value"
+            FROM drafted_players d
+            INNER JOIN players p ON p.id = d.player_id
+            LEFT JOIN projections pr ON pr.player_id = p.id
+            WHERE d.user_id = $1 AND d.drafted_by_me
+            ORDER BY d.drafted_at
+            "#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut positions: Vec<RosterPosition> = Position::iter()
+            .map(|position| RosterPosition {
+                position,
+                players: Vec::new(),
+            })
+            .collect();
+        let mut players = Vec::new();
+        let mut total_projected_points = 0.0;
+
+        for row in rows {
+            let mut projections = StatsResponse::from(row.projections);
+            scoring_service::apply_scoring(&mut projections, &row.position, &rules);
+            total_projected_points += projections.points.unwrap_or(0.0);
+
+            let player = RosterPlayer {
+                id: row.id,
+                name: row.name,
+                position: row.position,
+                team: row.team,
+                bye_week: row.bye_week,
+                projected_points: projections.points,
+            };
+            if let Some(group) = positions
+                .iter_mut()
+                .find(|group| group.position == player.position)
+            {
+                group.players.push(player.clone());
+            }
+            players.push(player);
+        }
+        positions.retain(|group| !group.players.is_empty());
+        let (slots, unassigned) = roster_service::assign_slots(&roster_settings, &players);
+
+        Ok(RosterResponse {
+            positions,
+            slots,
+            unassigned,
+            total_projected_points: round(total_projected_points, 1),
+        })
+    }
+}
+
+pub mod draft_event_operations {
+    use super::*;
+
+    pub(crate) async fn record_event(
+        user_id: i32,
+        event_type: DraftEventType,
+        target_event_id: Option<i32>,
+        drafted_players: &[DraftedPlayer],
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<DraftEvent, Error> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO draft_events (user_id, event_type, target_event_id, drafted_players)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, created_at
+            "#,
+            user_id,
+            &event_type as _,
+            target_event_id,
+            serde_json::to_value(drafted_players).unwrap()
+        )
+        .fetch_one(&mut **tx)
+        .await?;
+
+        Ok(DraftEvent {
+            id: row.id,
+            user_id,
+            event_type,
+            target_event_id,
+            drafted_players: drafted_players.to_vec(),
+            created_at: row.created_at,
+        })
+    }
+
+    async fn fetch_events(
+        user_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<DraftEvent>, Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                user_id,
+                event_type as "event_type!: DraftEventType",
+                target_event_id,
+                drafted_players,
+                created_at
+            FROM draft_events
+            WHERE user_id = $1
+            ORDER BY id
+            "#,
+            user_id
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(DraftEvent {
+                    id: row.id,
+                    user_id: row.user_id,
+                    event_type: row.event_type,
+                    target_event_id: row.target_event_id,
+                    drafted_players: serde_json::from_value(row.drafted_players)
+                        .map_err(|e| Error::Decode(Box::new(e)))?,
+                    created_at: row.created_at,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn get_events(user_id: i32) -> Result<Vec<DraftEvent>, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let events = fetch_events(user_id, &mut tx).await?;
+        tx.commit().await?;
+
+        Ok(events)
+    }
+
+    pub async fn replay(user_id: i32, up_to: Option<i32>) -> Result<Vec<DraftedPlayer>, Error> {
+        let events = get_events(user_id).await?;
+        Ok(draft_event_service::replay(&events, up_to))
+    }
+
+    // Puts an event's rows back on the board or takes them off again. Rows
+    // keep their original ids, picks and timestamps.
+    async fn apply_rows(
+        user_id: i32,
+        event: &DraftEvent,
+        forward: bool,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), DraftError> {
+        let player_ids: Vec<i32> = event
+            .drafted_players
+            .iter()
+            .map(|drafted_player| drafted_player.player_id)
+            .collect();
+
+        let rows_changed = if draft_event_service::adds_rows(event, forward) {
+            let already_drafted = sqlx::query_scalar!(
+                r#"
+                SELECT EXISTS (
+                    SELECT 1 FROM drafted_players
+                    WHERE user_id = $1 AND player_id = ANY($2)
+                ) as "exists!"
+                "#,
+                user_id,
+                &player_ids
+            )
+            .fetch_one(&mut **tx)
+            .await?;
+            if already_drafted {
+                return Err(DraftError::PlayerUnavailable);
+            }
+
+            let mut query_builder = QueryBuilder::new(
+                "INSERT INTO drafted_players (
+                    id, user_id, player_id, team_id, round, overall_pick, drafted_by_me, price,
+                    is_keeper, keeper_round, drafted_at
+                )",
+            );
+            query_builder.push_values(&event.drafted_players, |mut b, drafted_player| {
+                b.push_bind(drafted_player.id)
+                    .push_bind(drafted_player.user_id)
+                    .push_bind(drafted_player.player_id)
+                    .push_bind(drafted_player.team_id)
+                    .push_bind(drafted_player.round)
+                    .push_bind(drafted_player.overall_pick)
+                    .push_bind(drafted_player.drafted_by_me)
+                    .push_bind(drafted_player.price)
+                    .push_bind(drafted_player.is_keeper)
+                    .push_bind(drafted_player.keeper_round)
+                    .push_bind(drafted_player.drafted_at);
+            });
+            query_builder
+                .build()
+                .execute(&mut **tx)
+                .await?
+                .rows_affected()
+        } else {
+            sqlx::query!(
+                "DELETE FROM drafted_players WHERE user_id = $1 AND player_id = ANY($2)",
+                user_id,
+                &player_ids
+            )
+            .execute(&mut **tx)
+            .await?
+            .rows_affected()
+        };
+
+        if rows_changed > 0 {
+            league_operations::rewind_draft_clock(user_id, tx).await?;
+        }
+        Ok(())
+    }
+
+    // Reverts the latest draft, undraft or reset that hasn't been undone.
+    pub async fn undo(user_id: i32) -> Result<DraftEvent, DraftError> {
+        step(user_id, DraftEventType::Undo).await
+    }
+
+    // Re-applies the latest undone event, as long as nothing new has been
+    // drafted or undrafted since.
+    pub async fn redo(user_id: i32) -> Result<DraftEvent, DraftError> {
+        step(user_id, DraftEventType::Redo).await
+    }
+
+    async fn step(user_id: i32, event_type: DraftEventType) -> Result<DraftEvent, DraftError> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        // Serializes undo/redo per board so two requests can't pick the same
+        // target.
+        sqlx::query!("SELECT pg_advisory_xact_lock($1)", user_id as i64)
+            .execute(&mut *tx)
+            .await?;
+
+        let events = fetch_events(user_id, &mut tx).await?;
+        let forward = event_type == DraftEventType::Redo;
+        let (target_id, missing) = match forward {
+            true => (
+                draft_event_service::redo_target(&events),
+                DraftError::NothingToRedo,
+            ),
+            false => (
+                draft_event_service::undo_target(&events),
+                DraftError::NothingToUndo,
+            ),
+        };
+        let target = target_id
+            .and_then(|target_id| events.iter().find(|event| event.id == target_id))
+            .ok_or(missing)?;
+
+        apply_rows(user_id, target, forward, &mut tx).await?;
+        let event = record_event(user_id, event_type, Some(target.id), &[], &mut tx).await?;
+        tx.commit().await?;
+
+        Ok(event)
+    }
+}
+
+pub mod mock_draft_operations {
+    use super::*;
+
+    async fn get_mock_draft_row(
+        user_id: i32,
+        mock_draft_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Option<MockDraft>, Error> {
+        sqlx::query_as!(
+            MockDraft,
+            r#"
+            SELECT
+                id,
+                user_id,
+                draft_order as "draft_order!: DraftOrder",
+                team_count,
+                rounds,
+                draft_position,
+                seed,
+                current_pick,
+                created_at
+            FROM mock_drafts
+            WHERE id = $1 AND user_id = $2
+            FOR UPDATE
+            "#,
+            mock_draft_id,
+            user_id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+    }
+
+    async fn get_mock_draft_picks(
+        mock_draft_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<MockDraftPick>, Error> {
+        sqlx::query_as!(
+            MockDraftPick,
+            r#"
+            SELECT
+                m.overall_pick,
+                m.round,
+                m.draft_position,
+                m.player_id,
+                p.position as "position!: Position"
+            FROM mock_draft_picks m
+            INNER JOIN players p ON p.id = m.player_id
+            WHERE m.mock_draft_id = $1
+            ORDER BY m.overall_pick
+            "#,
+            mock_draft_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    // The user's board order, minus anyone already taken in this mock.
+    async fn get_candidates(
+        user_id: i32,
+        mock_draft_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<MockCandidate>, Error> {
+        sqlx::query_as!(
+            MockCandidate,
+            r#"
+            SELECT
+                p.id as player_id,
+                p.position as "position!: Position",
+                r.average as "average!",
+                r.standard_deviation as "standard_deviation!"
+            FROM players p
+            INNER JOIN users u ON u.id = $1
+            INNER JOIN rankings r ON p.id = r.player_id
+                AND r.scoring_settings = u.scoring_settings
+                AND r.league_format = u.league_format
+                AND r.ranking_type = u.ranking_type
+            WHERE (u.idp OR p.position NOT IN ('DL', 'LB', 'DB'))
+                AND p.id NOT IN (
+                    SELECT player_id FROM mock_draft_picks WHERE mock_draft_id = $2
+                )
+            ORDER BY r.overall ASC
+            "#,
+            user_id,
+            mock_draft_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    async fn record_pick(
+        mock_draft: &mut MockDraft,
+        player_id: i32,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), Error> {
+        let (round, draft_position) =
+            mock_draft_service::pick_position(mock_draft, mock_draft.current_pick);
+        sqlx::query!(
+            r#"
+            INSERT INTO mock_draft_picks (
+                mock_draft_id, overall_pick, round, draft_position, player_id
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            mock_draft.id,
+            mock_draft.current_pick,
+            round,
+            draft_position,
+            player_id
+        )
+        .execute(&mut **tx)
+        .await?;
+        sqlx::query!(
+            "UPDATE mock_drafts SET current_pick = current_pick + 1 WHERE id = $1",
+            mock_draft.id
+        )
+        .execute(&mut **tx)
+        .await?;
+        mock_draft.current_pick += 1;
+        Ok(())
+    }
+
+    // Lets the bots pick until it's the user's turn or the mock is over.
+    async fn advance_bots(
+        mock_draft: &mut MockDraft,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), Error> {
+        let roster_settings =
+            roster_settings_operations::get_roster_settings(mock_draft.user_id).await?;
+        let mut candidates = get_candidates(mock_draft.user_id, mock_draft.id, tx).await?;
+        let picks = get_mock_draft_picks(mock_draft.id, tx).await?;
+        let mut rosters: HashMap<i32, Vec<Position>> = HashMap::new();
+        for pick in picks {
+            rosters
+                .entry(pick.draft_position)
+                .or_default()
+                .push(pick.position);
+        }
+
+        while let Some(draft_position) = mock_draft_service::on_the_clock(mock_draft) {
+            if draft_position == mock_draft.draft_position {
+                break;
+            }
+
+            let (round, _) = mock_draft_service::pick_position(mock_draft, mock_draft.current_pick);
+            let mut rng = mock_draft_service::pick_rng(mock_draft, mock_draft.current_pick);
+            let bot_roster = rosters.entry(draft_position).or_default();
+            let Some(player_id) = mock_draft_service::bot_pick(
+                &candidates,
+                bot_roster,
+                round,
+                mock_draft.rounds,
+                &roster_settings,
+                &mut rng,
+            ) else {
+                break;
+            };
+
+            if let Some(index) = candidates
+                .iter()
+                .position(|candidate| candidate.player_id == player_id)
+            {
+                bot_roster.push(candidates.remove(index).position);
+            }
+            record_pick(mock_draft, player_id, tx).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn build_response(
+        mock_draft: MockDraft,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<MockDraftResponse, Error> {
+        let picks = get_mock_draft_picks(mock_draft.id, tx).await?;
+        let on_the_clock = mock_draft_service::on_the_clock(&mock_draft);
+        Ok(MockDraftResponse {
+            mock_draft,
+            picks,
+            on_the_clock,
+        })
+    }
+
+    // Starts a mock and runs the bots up to the user's first pick.
+    pub async fn create_mock_draft(
+        user_id: i32,
+        request: &CreateMockDraftRequest,
+    ) -> Result<MockDraftResponse, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let seed = request
+            .seed
+            .unwrap_or_else(|| rand::thread_rng().gen_range(0..i64::MAX));
+
+        let mut mock_draft = sqlx::query_as!(
+            MockDraft,
+            r#"
+            INSERT INTO mock_drafts (
+                user_id, draft_order, team_count, rounds, draft_position, seed
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING
+                id,
+                user_id,
+                draft_order as "draft_order!: DraftOrder",
+                team_count,
+                rounds,
+                draft_position,
+                seed,
+                current_pick,
+                created_at
+            "#,
+            user_id,
+            &request.draft_order as _,
+            request.team_count,
+            request.rounds,
+            request.draft_position,
+            seed
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        advance_bots(&mut mock_draft, &mut tx).await?;
+        let response = build_response(mock_draft, &mut tx).await?;
+        tx.commit().await?;
+
+        Ok(response)
+    }
+
+    pub async fn get_mock_draft(
+        user_id: i32,
+        mock_draft_id: i32,
+    ) -> Result<Option<MockDraftResponse>, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let response = match get_mock_draft_row(user_id, mock_draft_id, &mut tx).await? {
+            Some(mock_draft) => Some(build_response(mock_draft, &mut tx).await?),
+            None => None,
+        };
+        tx.commit().await?;
+
+        Ok(response)
+    }
+
+    // Records the user's pick, then lets the bots run to the user's next turn.
+    pub async fn make_pick(
+        user_id: i32,
+        mock_draft_id: i32,
+        player_id: i32,
+    ) -> Result<Option<MockDraftResponse>, DraftError> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let mut mock_draft = match get_mock_draft_row(user_id, mock_draft_id, &mut tx).await? {
+            Some(mock_draft) => mock_draft,
+            None => return Ok(None),
+        };
+
+        match mock_draft_service::on_the_clock(&mock_draft) {
+            None => return Err(DraftError::DraftComplete),
+            Some(draft_position) if draft_position != mock_draft.draft_position => {
+                return Err(DraftError::OutOfTurn {
+                    on_the_clock: draft_position,
+                });
+            }
+            Some(_) => (),
+        }
+        let candidates = get_candidates(user_id, mock_draft.id, &mut tx).await?;
+        if !candidates
+            .iter()
+            .any(|candidate| candidate.player_id == player_id)
+        {
+            return Err(DraftError::PlayerUnavailable);
+        }
+
+        record_pick(&mut mock_draft, player_id, &mut tx).await?;
+        advance_bots(&mut mock_draft, &mut tx).await?;
+        let response = build_response(mock_draft, &mut tx).await?;
+        tx.commit().await?;
+
+        Ok(Some(response))
+    }
+
+    pub async fn delete_mock_draft(user_id: i32, mock_draft_id: i32) -> Result<bool, Error> {
+        let pool = get_pool()?;
+        let mut tx = pool.begin().await?;
+        let result = sqlx::query!(
+            "DELETE FROM mock_drafts WHERE id = $1 AND user_id = $2",
+            mock_draft_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        if result.rows_affected() > 0 {
+            sqlx::query!(
+                "DELETE FROM mock_draft_picks WHERE mock_draft_id = $1",
+                mock_draft_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(result.rows_affected() > 0)
     }
 }
@@ -5,7 +5,7 @@
 
 -- Enum types. CREATE TYPE has no IF NOT EXISTS, so swallow the duplicate error.
 DO $$ BEGIN
-    CREATE TYPE position_type AS ENUM ('QB', 'RB', 'WR', 'TE', 'K', 'DST');
+    CREATE TYPE position_type AS ENUM ('QB', 'RB', 'WR', 'TE', 'K', 'DST', 'DL', 'LB', 'DB');
 EXCEPTION WHEN duplicate_object THEN NULL;
 END $$;
 
@@ -24,6 +24,26 @@ DO $$ BEGIN
 EXCEPTION WHEN duplicate_object THEN NULL;
 END $$;
 
+DO $$ BEGIN
+    CREATE TYPE league_format_type AS ENUM ('OneQB', 'Superflex');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE ranking_type_type AS ENUM ('Redraft', 'Dynasty', 'Rookie', 'BestBall');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE draft_order_type AS ENUM ('Snake', 'Linear', 'ThirdRoundReversal');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE draft_event_type AS ENUM ('Draft', 'Undraft', 'Reset', 'Undo', 'Redo');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
 -- Create tables
 CREATE TABLE IF NOT EXISTS players (
     id INTEGER PRIMARY KEY,
@@ -40,17 +60,20 @@ CREATE TABLE IF NOT EXISTS players (
 CREATE TABLE IF NOT EXISTS rankings (
     player_id INTEGER,
     scoring_settings scoring_settings_type,
+    league_format league_format_type NOT NULL DEFAULT 'OneQB',
+    ranking_type ranking_type_type NOT NULL DEFAULT 'Redraft',
     overall INTEGER,
     position INTEGER,
     best INTEGER,
     worst INTEGER,
     average REAL,
     standard_deviation REAL,
-    PRIMARY KEY (player_id, scoring_settings)
+    PRIMARY KEY (player_id, scoring_settings, league_format, ranking_type)
 );
 
 CREATE TABLE IF NOT EXISTS stats (
-    player_id INTEGER PRIMARY KEY,
+    player_id INTEGER,
+    season INTEGER NOT NULL,
     pass_cmp DOUBLE PRECISION,
     pass_att DOUBLE PRECISION,
     pass_cmp_pct DOUBLE PRECISION,
@@ -92,19 +115,76 @@ CREATE TABLE IF NOT EXISTS stats (
     def_td DOUBLE PRECISION,
     safeties DOUBLE PRECISION,
     special_teams_td DOUBLE PRECISION,
+    points_allowed DOUBLE PRECISION,
+    yards_allowed DOUBLE PRECISION,
+    tackles DOUBLE PRECISION,
+    assists DOUBLE PRECISION,
+    tackles_for_loss DOUBLE PRECISION,
+    qb_hits DOUBLE PRECISION,
+    passes_defended DOUBLE PRECISION,
+    idp_sacks DOUBLE PRECISION,
+    idp_int DOUBLE PRECISION,
+    idp_fumbles_forced DOUBLE PRECISION,
+    idp_fumbles_recovered DOUBLE PRECISION,
+    idp_td DOUBLE PRECISION,
     games DOUBLE PRECISION,
-    standard_pts DOUBLE PRECISION,
-    standard_pts_per_game DOUBLE PRECISION,
-    half_ppr_pts DOUBLE PRECISION,
-    half_ppr_pts_per_game DOUBLE PRECISION,
-    ppr_pts DOUBLE PRECISION,
-    ppr_pts_per_game DOUBLE PRECISION
+    PRIMARY KEY (player_id, season)
+);
+
+CREATE TABLE IF NOT EXISTS projections (
+    player_id INTEGER PRIMARY KEY,
+    pass_att DOUBLE PRECISION,
+    pass_cmp DOUBLE PRECISION,
+    pass_yds DOUBLE PRECISION,
+    pass_td DOUBLE PRECISION,
+    pass_int DOUBLE PRECISION,
+    rush_att DOUBLE PRECISION,
+    rush_yds DOUBLE PRECISION,
+    rush_td DOUBLE PRECISION,
+    receptions DOUBLE PRECISION,
+    rec_yds DOUBLE PRECISION,
+    rec_td DOUBLE PRECISION,
+    fumbles DOUBLE PRECISION,
+    field_goals DOUBLE PRECISION,
+    fg_att DOUBLE PRECISION,
+    extra_points DOUBLE PRECISION,
+    sacks DOUBLE PRECISION,
+    int DOUBLE PRECISION,
+    fumbles_recovered DOUBLE PRECISION,
+    fumbles_forced DOUBLE PRECISION,
+    def_td DOUBLE PRECISION,
+    safeties DOUBLE PRECISION,
+    points_allowed DOUBLE PRECISION,
+    yards_allowed DOUBLE PRECISION,
+    games DOUBLE PRECISION
+);
+
+-- Weekly lines are only ever read back whole, so the stat fields are kept as
+-- a serialized StatsBase rather than one column each.
+CREATE TABLE IF NOT EXISTS game_logs (
+    player_id INTEGER,
+    season INTEGER,
+    week INTEGER,
+    stats JSONB NOT NULL,
+    PRIMARY KEY (player_id, season, week)
 );
 
 CREATE TABLE IF NOT EXISTS users (
     id SERIAL PRIMARY KEY,
     username VARCHAR(255) NOT NULL UNIQUE,
     scoring_settings scoring_settings_type,
+    league_format league_format_type NOT NULL DEFAULT 'OneQB',
+    ranking_type ranking_type_type NOT NULL DEFAULT 'Redraft',
+    scoring_profile_id INTEGER,
+    idp BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS scoring_profiles (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    rules JSONB NOT NULL,
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL
 );
 
@@ -112,10 +192,96 @@ CREATE TABLE IF NOT EXISTS drafted_players (
     id SERIAL PRIMARY KEY,
     user_id INTEGER NOT NULL,
     player_id INTEGER NOT NULL,
+    team_id INTEGER,
+    round INTEGER,
+    overall_pick INTEGER,
+    drafted_by_me BOOLEAN NOT NULL DEFAULT FALSE,
+    price INTEGER,
+    is_keeper BOOLEAN NOT NULL DEFAULT FALSE,
+    keeper_round INTEGER,
     drafted_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL,
     UNIQUE(user_id, player_id)
 );
 
+-- Append-only: rows are never updated or deleted.
+CREATE TABLE IF NOT EXISTS draft_events (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    event_type draft_event_type NOT NULL,
+    target_event_id INTEGER,
+    drafted_players JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS leagues (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    draft_order draft_order_type NOT NULL,
+    rounds INTEGER NOT NULL,
+    current_pick INTEGER NOT NULL DEFAULT 1,
+    auction_budget INTEGER,
+    pick_seconds INTEGER,
+    pick_deadline TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS league_teams (
+    id SERIAL PRIMARY KEY,
+    league_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    draft_position INTEGER NOT NULL,
+    UNIQUE(league_id, draft_position)
+);
+
+-- Boards without a row use the default roster.
+CREATE TABLE IF NOT EXISTS roster_settings (
+    user_id INTEGER PRIMARY KEY,
+    qb INTEGER NOT NULL,
+    rb INTEGER NOT NULL,
+    wr INTEGER NOT NULL,
+    te INTEGER NOT NULL,
+    flex INTEGER NOT NULL,
+    superflex INTEGER NOT NULL,
+    k INTEGER NOT NULL,
+    dst INTEGER NOT NULL,
+    dl INTEGER NOT NULL,
+    lb INTEGER NOT NULL,
+    db INTEGER NOT NULL,
+    bench INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS draft_picks (
+    league_id INTEGER NOT NULL,
+    overall_pick INTEGER NOT NULL,
+    round INTEGER NOT NULL,
+    original_team_id INTEGER NOT NULL,
+    team_id INTEGER NOT NULL,
+    PRIMARY KEY (league_id, overall_pick)
+);
+
+CREATE TABLE IF NOT EXISTS mock_drafts (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    draft_order draft_order_type NOT NULL,
+    team_count INTEGER NOT NULL,
+    rounds INTEGER NOT NULL,
+    draft_position INTEGER NOT NULL,
+    seed BIGINT NOT NULL,
+    current_pick INTEGER NOT NULL DEFAULT 1,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS mock_draft_picks (
+    mock_draft_id INTEGER NOT NULL,
+    overall_pick INTEGER NOT NULL,
+    round INTEGER NOT NULL,
+    draft_position INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    PRIMARY KEY (mock_draft_id, overall_pick),
+    UNIQUE(mock_draft_id, player_id)
+);
+
 CREATE TABLE IF NOT EXISTS fantasy_data_updates (
     id SERIAL PRIMARY KEY,
     completed_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL
@@ -126,3 +292,72 @@ CREATE TABLE IF NOT EXISTS fantasy_data_updates (
 -- every column added after initial release gets an ADD COLUMN IF NOT EXISTS
 -- line here to backfill older databases.
 ALTER TABLE stats ADD COLUMN IF NOT EXISTS rec_tgt_pct DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS points_allowed DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS yards_allowed DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS tackles DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS assists DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS tackles_for_loss DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS qb_hits DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS passes_defended DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS idp_sacks DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS idp_int DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS idp_fumbles_forced DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS idp_fumbles_recovered DOUBLE PRECISION;
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS idp_td DOUBLE PRECISION;
+ALTER TYPE position_type ADD VALUE IF NOT EXISTS 'DL';
+ALTER TYPE position_type ADD VALUE IF NOT EXISTS 'LB';
+ALTER TYPE position_type ADD VALUE IF NOT EXISTS 'DB';
+ALTER TABLE users ADD COLUMN IF NOT EXISTS idp BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS scoring_profile_id INTEGER;
+-- Fantasy points are now computed from the raw stats at read time, so the
+-- per-preset point columns older databases have are dropped.
+ALTER TABLE stats
+    DROP COLUMN IF EXISTS standard_pts,
+    DROP COLUMN IF EXISTS standard_pts_per_game,
+    DROP COLUMN IF EXISTS half_ppr_pts,
+    DROP COLUMN IF EXISTS half_ppr_pts_per_game,
+    DROP COLUMN IF EXISTS ppr_pts,
+    DROP COLUMN IF EXISTS ppr_pts_per_game;
+-- Stats gained a season dimension. Rows saved before then are last season's
+-- relative to the refresh that wrote them, and the key widens to (player, season).
+ALTER TABLE stats ADD COLUMN IF NOT EXISTS season INTEGER;
+UPDATE stats SET season = (
+    SELECT EXTRACT(YEAR FROM COALESCE(MAX(completed_at), CURRENT_TIMESTAMP))::INTEGER - 1
+    FROM fantasy_data_updates
+) WHERE season IS NULL;
+ALTER TABLE stats ALTER COLUMN season SET NOT NULL;
+DO $$ BEGIN
+    IF (SELECT array_length(conkey, 1) FROM pg_constraint WHERE conname = 'stats_pkey') = 1 THEN
+        ALTER TABLE stats DROP CONSTRAINT stats_pkey;
+        ALTER TABLE stats ADD PRIMARY KEY (player_id, season);
+    END IF;
+END $$;
+-- Rankings gained a league format dimension. Existing rows are the 1QB sheets.
+ALTER TABLE rankings ADD COLUMN IF NOT EXISTS league_format league_format_type NOT NULL DEFAULT 'OneQB';
+DO $$ BEGIN
+    IF (SELECT array_length(conkey, 1) FROM pg_constraint WHERE conname = 'rankings_pkey') = 2 THEN
+        ALTER TABLE rankings DROP CONSTRAINT rankings_pkey;
+        ALTER TABLE rankings ADD PRIMARY KEY (player_id, scoring_settings, league_format);
+    END IF;
+END $$;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS league_format league_format_type NOT NULL DEFAULT 'OneQB';
+-- ...and then a ranking type dimension. Existing rows are the redraft sheets.
+ALTER TABLE rankings ADD COLUMN IF NOT EXISTS ranking_type ranking_type_type NOT NULL DEFAULT 'Redraft';
+DO $$ BEGIN
+    IF (SELECT array_length(conkey, 1) FROM pg_constraint WHERE conname = 'rankings_pkey') = 3 THEN
+        ALTER TABLE rankings DROP CONSTRAINT rankings_pkey;
+        ALTER TABLE rankings ADD PRIMARY KEY (player_id, scoring_settings, league_format, ranking_type);
+    END IF;
+END $$;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS ranking_type ranking_type_type NOT NULL DEFAULT 'Redraft';
+ALTER TYPE ranking_type_type ADD VALUE IF NOT EXISTS 'BestBall';
+ALTER TABLE drafted_players ADD COLUMN IF NOT EXISTS team_id INTEGER;
+ALTER TABLE drafted_players ADD COLUMN IF NOT EXISTS round INTEGER;
+ALTER TABLE drafted_players ADD COLUMN IF NOT EXISTS overall_pick INTEGER;
+ALTER TABLE drafted_players ADD COLUMN IF NOT EXISTS drafted_by_me BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS auction_budget INTEGER;
+ALTER TABLE drafted_players ADD COLUMN IF NOT EXISTS price INTEGER;
+ALTER TABLE drafted_players ADD COLUMN IF NOT EXISTS is_keeper BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE drafted_players ADD COLUMN IF NOT EXISTS keeper_round INTEGER;
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS pick_seconds INTEGER;
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS pick_deadline TIMESTAMP WITH TIME ZONE;
@@ -15,6 +15,7 @@ async fn main() -> std::io::Result<()> {
     init_pool()
         .await
         .expect("Failed to initialize database pool");
+    services::pick_clock_service::spawn();
 
     let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
     let port = std::env::var("PORT")
@@ -24,12 +25,40 @@ async fn main() -> std::io::Result<()> {
 
     HttpServer::new(move || {
         App::new()
+            .service(routes::draft_events::get_draft_events)
+            .service(routes::draft_rooms::join_draft_room)
+            .service(routes::draft_events::replay_draft)
+            .service(routes::draft_events::undo_draft_event)
+            .service(routes::draft_events::redo_draft_event)
             .service(routes::drafted_players::draft_player)
+            .service(routes::drafted_players::keep_player)
             .service(routes::drafted_players::reset_board)
             .service(routes::drafted_players::undraft_player)
+            .service(routes::drafted_players::get_roster)
+            .service(routes::drafted_players::get_roster_settings)
+            .service(routes::drafted_players::update_roster_settings)
+            .service(routes::events::get_events)
             .service(routes::fantasy_data::get_last_update)
             .service(routes::fantasy_data::update_fantasy_data)
+            .service(routes::leagues::get_league)
+            .service(routes::leagues::create_league)
+            .service(routes::leagues::set_pick_clock)
+            .service(routes::leagues::trade_picks)
+            .service(routes::leagues::delete_league)
+            .service(routes::mock_drafts::create_mock_draft)
+            .service(routes::mock_drafts::get_mock_draft)
+            .service(routes::mock_drafts::make_mock_pick)
+            .service(routes::mock_drafts::delete_mock_draft)
             .service(routes::players::get_players)
+            .service(routes::players::get_player_stats)
+            .service(routes::players::get_player_game_logs)
+            .service(routes::recommendations::get_recommendations)
+            .service(routes::scoring_profiles::get_scoring_presets)
+            .service(routes::scoring_profiles::get_scoring_profiles)
+            .service(routes::scoring_profiles::get_scoring_profile)
+            .service(routes::scoring_profiles::create_scoring_profile)
+            .service(routes::scoring_profiles::update_scoring_profile)
+            .service(routes::scoring_profiles::delete_scoring_profile)
             .service(routes::users::create_user)
             .service(routes::users::get_user)
             .service(routes::users::update_user)
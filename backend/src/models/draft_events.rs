@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use strum::{Display, EnumString};
+use time::OffsetDateTime;
+
+use crate::models::drafted_players::DraftedPlayer;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumString, Display, Type)]
+#[sqlx(type_name = "draft_event_type")]
+pub enum DraftEventType {
+    Draft,
+    Undraft,
+    Reset,
+    Undo,
+    Redo,
+}
+
+// One entry in a board's append-only draft log. Draft, undraft and reset
+// events carry the drafted player rows they added or removed; undo and redo
+// events point at the event they reverted or re-applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftEvent {
+    pub id: i32,
+    pub user_id: i32,
+    pub event_type: DraftEventType,
+    pub target_event_id: Option<i32>,
+    pub drafted_players: Vec<DraftedPlayer>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Deserialize)]
+pub struct ReplayQuery {
+    // Replays the whole log when omitted.
+    pub event_id: Option<i32>,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::drafted_players::DraftedPlayer;
+use crate::models::users::User;
+
+// Pushed to everyone connected to a board's draft room or event stream. A
+// board's room is keyed by the user id that owns the board.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum DraftRoomMessage {
+    Drafted { drafted_player: DraftedPlayer },
+    Undrafted { player_id: i32 },
+    Reset,
+    // Anything else that changes the board or league (undo/redo, creating or
+    // deleting the league, pick trades, the pick clock), so clients refetch
+    // both.
+    BoardChanged,
+    // The board owner's scoring settings changed, so rankings need refetching.
+    UserUpdated { user: User },
+    // Sent to every board.
+    FantasyDataRefreshed,
+    // Sent whenever someone joins or leaves, with everyone still connected.
+    Presence { connected: Vec<String> },
+}
+
+#[derive(Deserialize)]
+pub struct DraftRoomQuery {
+    // Shown to the rest of the room.
+    pub name: String,
+}
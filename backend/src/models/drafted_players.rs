@@ -1,10 +1,53 @@
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DraftedPlayer {
     pub id: i32,
     pub user_id: i32,
     pub player_id: i32,
+    // Only set for picks made in a league draft.
+    pub team_id: Option<i32>,
+    pub round: Option<i32>,
+    pub overall_pick: Option<i32>,
+    pub drafted_by_me: bool,
+    // Winning bid in an auction league.
+    pub price: Option<i32>,
+    pub is_keeper: bool,
+    pub keeper_round: Option<i32>,
     pub drafted_at: OffsetDateTime,
 }
+
+#[derive(Debug, Serialize)]
+pub struct DraftResponse {
+    #[serde(flatten)]
+    pub drafted_player: DraftedPlayer,
+    // Set when one of my picks doesn't fit any open roster slot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+// In a league draft the pick goes to the team on the clock. Naming a team is
+// optional, but a pick for any other team is refused. In an auction league
+// `team_id` is the winning team and `price` its winning bid, and both are
+// required. `drafted_by_me` marks picks that belong to my roster rather than
+// an opponent's. Passing the `overall_pick` the client thinks is up refuses
+// the pick if the board has moved on since.
+#[derive(Deserialize, Default)]
+pub struct DraftRequest {
+    pub team_id: Option<i32>,
+    #[serde(default)]
+    pub drafted_by_me: bool,
+    pub price: Option<i32>,
+    pub overall_pick: Option<i32>,
+}
+
+// Keepers count as drafted before the draft starts. In a league the keeper
+// fills `team_id`'s pick in `keeper_round`, which the clock then skips.
+#[derive(Deserialize)]
+pub struct KeeperRequest {
+    pub keeper_round: i32,
+    pub team_id: Option<i32>,
+    #[serde(default)]
+    pub drafted_by_me: bool,
+}
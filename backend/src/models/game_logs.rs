@@ -0,0 +1,23 @@
+use crate::models::stats::{StatsBase, StatsResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameLog {
+    pub player_id: i32,
+    pub season: i32,
+    pub week: i32,
+    pub base: StatsBase,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameLogResponse {
+    pub week: i32,
+    #[serde(flatten)]
+    pub stats: StatsResponse,
+}
+
+#[derive(Deserialize)]
+pub struct GameLogsQuery {
+    // Defaults to the player's latest season with game logs.
+    pub season: Option<i32>,
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use strum::{Display, EnumString};
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, EnumString, Display, Type)]
+#[sqlx(type_name = "draft_order_type")]
+pub enum DraftOrder {
+    #[default]
+    Snake,
+    Linear,
+    // Snake, except round 3 runs in the same direction as round 2.
+    ThirdRoundReversal,
+}
+
+// Each user board can run one league draft. `current_pick` is the overall
+// pick number that's on the clock. Leagues with an `auction_budget` run an
+// auction instead: the team on the clock nominates and any team can win the
+// player, with `rounds` as the roster size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct League {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub draft_order: DraftOrder,
+    pub rounds: i32,
+    pub current_pick: i32,
+    pub auction_budget: Option<i32>,
+    // With a pick clock running, the team on the clock is autopicked for once
+    // `pick_deadline` passes.
+    pub pick_seconds: Option<i32>,
+    pub pick_deadline: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeagueTeam {
+    pub id: i32,
+    pub league_id: i32,
+    pub name: String,
+    pub draft_position: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftSlot {
+    pub overall_pick: i32,
+    pub round: i32,
+    pub team_id: i32,
+}
+
+// A pick slot in a snake or linear league. Picks start with the team whose
+// draft position makes them and change hands through trades; `team_id` is
+// the current owner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftPick {
+    pub league_id: i32,
+    pub overall_pick: i32,
+    pub round: i32,
+    pub original_team_id: i32,
+    pub team_id: i32,
+}
+
+// A team's money left and the most it can bid while still affording $1 for
+// each other open roster slot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamBudget {
+    pub team_id: i32,
+    pub remaining_budget: i32,
+    pub open_slots: i32,
+    pub max_bid: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeagueResponse {
+    #[serde(flatten)]
+    pub league: League,
+    pub teams: Vec<LeagueTeam>,
+    // Empty for auction leagues.
+    pub picks: Vec<DraftPick>,
+    // Empty once every round has been drafted.
+    pub on_the_clock: Option<DraftSlot>,
+    // Only for auction leagues.
+    pub budgets: Option<Vec<TeamBudget>>,
+    pub pick_seconds_remaining: Option<i64>,
+}
+
+// Starts the clock for the pick that's up, or stops it when empty.
+#[derive(Deserialize)]
+pub struct PickClockRequest {
+    pub pick_seconds: Option<i32>,
+}
+
+// Swaps picks (by overall pick number) between two teams: `team_id` sends
+// `sends` and gets `receives` from `other_team_id`. Either side may be empty
+// for a one-way deal.
+#[derive(Deserialize)]
+pub struct TradePicksRequest {
+    pub team_id: i32,
+    pub other_team_id: i32,
+    #[serde(default)]
+    pub sends: Vec<i32>,
+    #[serde(default)]
+    pub receives: Vec<i32>,
+}
+
+// Team names are given in first-round draft order.
+#[derive(Deserialize)]
+pub struct CreateLeagueRequest {
+    pub name: String,
+    pub draft_order: DraftOrder,
+    pub rounds: i32,
+    pub teams: Vec<String>,
+    #[serde(default)]
+    pub auction_budget: Option<i32>,
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::models::leagues::DraftOrder;
+use crate::models::players::Position;
+
+// A practice draft against bots, kept apart from the real board. The user
+// picks from `draft_position`; every other team is a bot. `seed` makes the
+// bots' picks reproducible.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockDraft {
+    pub id: i32,
+    pub user_id: i32,
+    pub draft_order: DraftOrder,
+    pub team_count: i32,
+    pub rounds: i32,
+    pub draft_position: i32,
+    pub seed: i64,
+    pub current_pick: i32,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockDraftPick {
+    pub overall_pick: i32,
+    pub round: i32,
+    pub draft_position: i32,
+    pub player_id: i32,
+    pub position: Position,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MockDraftResponse {
+    #[serde(flatten)]
+    pub mock_draft: MockDraft,
+    pub picks: Vec<MockDraftPick>,
+    // Draft position on the clock; empty once the mock is over.
+    pub on_the_clock: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateMockDraftRequest {
+    pub team_count: i32,
+    pub rounds: i32,
+    pub draft_position: i32,
+    #[serde(default)]
+    pub draft_order: DraftOrder,
+    pub seed: Option<i64>,
+}
+
+// A player still available to the bots, with the ranking spread they use as
+// a noisy ADP.
+#[derive(Debug, Clone)]
+pub struct MockCandidate {
+    pub player_id: i32,
+    pub position: Position,
+    pub average: f32,
+    pub standard_deviation: f32,
+}
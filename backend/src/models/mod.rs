@@ -1,5 +1,14 @@
+pub mod draft_events;
+pub mod draft_rooms;
 pub mod drafted_players;
+pub mod game_logs;
+pub mod leagues;
+pub mod mock_drafts;
 pub mod players;
+pub mod projections;
 pub mod rankings;
+pub mod recommendations;
+pub mod roster;
+pub mod scoring_profiles;
 pub mod stats;
 pub mod users;
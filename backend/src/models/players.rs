@@ -1,11 +1,17 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
 use crate::models::rankings::RankingsBase;
 use crate::models::stats::StatsResponse;
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use strum::{Display, EnumIter, EnumString};
 
-#[derive(Debug, Clone, Serialize, Deserialize, EnumString, Display, EnumIter, Type)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq, EnumString, Display, EnumIter, Type,
+)]
 #[sqlx(type_name = "position_type")]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Position {
     QB,
     RB,
@@ -13,10 +19,18 @@ pub enum Position {
     TE,
     K,
     DST,
+    // IDP sheets sometimes list the specific spot rather than the group.
+    #[strum(to_string = "DL", serialize = "DE", serialize = "DT")]
+    DL,
+    #[strum(to_string = "LB", serialize = "ILB", serialize = "OLB")]
+    LB,
+    #[strum(to_string = "DB", serialize = "CB", serialize = "S")]
+    DB,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, EnumString, Display, EnumIter, Type)]
 #[sqlx(type_name = "team_type")]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Team {
     ARI,
     ATL,
@@ -89,6 +103,69 @@ pub struct PlayerTask {
     pub position: Position,
 }
 
+// How the replacement player at each position is picked for VBD: the last
+// starter across the league, the last player expected to be rostered once
+// benches are filled, or a manual rank per position.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+pub enum BaselineMethod {
+    #[default]
+    LastStarter,
+    StarterPlusBench,
+    Manual,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+pub enum PointsSource {
+    #[default]
+    Projections,
+    Stats,
+}
+
+#[derive(Deserialize, Default)]
+pub struct PlayersQuery {
+    // Defaults to the latest season with stats.
+    pub season: Option<i32>,
+    // Auction values default to the league's budget and team count, or $200
+    // and 12 teams without a league.
+    pub budget: Option<i32>,
+    pub teams: Option<i32>,
+    #[serde(default)]
+    pub baseline: BaselineMethod,
+    #[serde(default)]
+    pub vbd_source: PointsSource,
+    // Higher values split players into more tiers.
+    pub tier_sensitivity: Option<f64>,
+    // Manual baselines as position:rank pairs, e.g. "QB:12,RB:30". Positions
+    // left out use the last starter.
+    pub baselines: Option<String>,
+}
+
+impl PlayersQuery {
+    pub fn manual_baselines(&self) -> Result<HashMap<Position, usize>, String> {
+        let Some(baselines) = &self.baselines else {
+            return Ok(HashMap::new());
+        };
+        baselines
+            .split(',')
+            .filter(|pair| !pair.trim().is_empty())
+            .map(|pair| {
+                let (position, rank) = pair
+                    .split_once(':')
+                    .ok_or_else(|| format!("Baseline \"{}\" should look like RB:30", pair))?;
+                let position = Position::from_str(position.trim())
+                    .map_err(|_| format!("Unknown position \"{}\"", position))?;
+                let rank = rank
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|rank| *rank > 0)
+                    .ok_or_else(|| format!("Baseline rank \"{}\" should be at least 1", rank))?;
+                Ok((position, rank))
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerResponse {
     pub id: i32,
@@ -102,5 +179,14 @@ pub struct PlayerResponse {
     pub college: String,
     pub rankings: RankingsBase,
     pub stats: StatsResponse,
+    pub projections: StatsResponse,
     pub drafted: bool,
+    pub drafted_by_me: bool,
+    pub keeper_round: Option<i32>,
+    // Rounds gained by keeping the player; see `draft_service::keeper_value`.
+    pub keeper_value: Option<i32>,
+    pub auction_value: Option<i32>,
+    // Points over the position's replacement baseline; see `vbd_service`.
+    pub vorp: Option<f64>,
+    pub vbd_rank: Option<i32>,
 }
@@ -0,0 +1,9 @@
+use crate::models::stats::StatsBase;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Projections {
+    pub player_id: i32,
+    #[serde(flatten)]
+    pub base: StatsBase,
+}
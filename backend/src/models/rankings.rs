@@ -6,12 +6,62 @@ use strum::{Display, EnumIter, EnumString};
     Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq, EnumString, Display, EnumIter, Type,
 )]
 #[sqlx(type_name = "scoring_settings_type")]
+#[allow(clippy::upper_case_acronyms)]
 pub enum ScoringSettings {
     Standard,
     Half,
     PPR,
 }
 
+// Superflex/2QB sheets rank quarterbacks far higher, so they're scraped as a
+// separate set alongside each scoring preset.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Hash,
+    Eq,
+    PartialEq,
+    EnumString,
+    Display,
+    EnumIter,
+    Type,
+)]
+#[sqlx(type_name = "league_format_type")]
+pub enum LeagueFormat {
+    #[default]
+    OneQB,
+    Superflex,
+}
+
+// Which FantasyPros consensus the board follows. Rookie sheets only rank
+// incoming rookies, which also narrows the board to that player pool. Best-ball
+// boards are also valued on weekly spike weeks instead of season totals.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    Hash,
+    Eq,
+    PartialEq,
+    EnumString,
+    Display,
+    EnumIter,
+    Type,
+)]
+#[sqlx(type_name = "ranking_type_type")]
+pub enum RankingType {
+    #[default]
+    Redraft,
+    Dynasty,
+    Rookie,
+    BestBall,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RankingsBase {
     pub overall: i32,
@@ -20,6 +70,11 @@ pub struct RankingsBase {
     pub worst: i32,
     pub average: f32,
     pub standard_deviation: f32,
+    // Positional and overall tiers; see `tier_service`. Not stored.
+    #[serde(default)]
+    pub tier: Option<i32>,
+    #[serde(default)]
+    pub overall_tier: Option<i32>,
 }
 
 impl From<serde_json::Value> for RankingsBase {
@@ -32,6 +87,8 @@ impl From<serde_json::Value> for RankingsBase {
 pub struct Rankings {
     pub player_id: i32,
     pub scoring_settings: ScoringSettings,
+    pub league_format: LeagueFormat,
+    pub ranking_type: RankingType,
     #[serde(flatten)]
     pub base: RankingsBase,
 }
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::players::PlayerResponse;
+
+#[derive(Debug, Serialize)]
+pub struct Recommendation {
+    pub player: PlayerResponse,
+    // Consensus rank adjusted for my roster; lower is better.
+    pub score: f64,
+    pub explanation: String,
+}
+
+#[derive(Deserialize)]
+pub struct RecommendationsQuery {
+    pub limit: Option<usize>,
+}
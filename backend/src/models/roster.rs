@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::players::{Position, Team};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RosterPlayer {
+    pub id: i32,
+    pub name: String,
+    pub position: Position,
+    pub team: Team,
+    pub bye_week: Option<i32>,
+    pub projected_points: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RosterPosition {
+    pub position: Position,
+    pub players: Vec<RosterPlayer>,
+}
+
+// In lineup order.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Slot {
+    QB,
+    RB,
+    WR,
+    TE,
+    FLEX,
+    SUPERFLEX,
+    K,
+    DST,
+    DL,
+    LB,
+    DB,
+    BENCH,
+}
+
+// An open slot has no player.
+#[derive(Debug, Serialize)]
+pub struct RosterSlot {
+    pub slot: Slot,
+    pub player: Option<RosterPlayer>,
+}
+
+// The players marked as drafted by me, grouped by position in board order and
+// assigned to the board's roster slots. `unassigned` holds players that didn't
+// fit any slot, bench included.
+#[derive(Debug, Serialize)]
+pub struct RosterResponse {
+    pub positions: Vec<RosterPosition>,
+    pub slots: Vec<RosterSlot>,
+    pub unassigned: Vec<RosterPlayer>,
+    pub total_projected_points: f64,
+}
+
+// Starting slots per team, plus the bench. Flex slots can hold a RB, WR or
+// TE, and superflex slots a QB as well.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterSettings {
+    pub qb: i32,
+    pub rb: i32,
+    pub wr: i32,
+    pub te: i32,
+    pub flex: i32,
+    pub superflex: i32,
+    pub k: i32,
+    pub dst: i32,
+    pub dl: i32,
+    pub lb: i32,
+    pub db: i32,
+    pub bench: i32,
+}
+
+impl Default for RosterSettings {
+    fn default() -> Self {
+        RosterSettings {
+            qb: 1,
+            rb: 2,
+            wr: 2,
+            te: 1,
+            flex: 1,
+            superflex: 0,
+            k: 1,
+            dst: 1,
+            dl: 0,
+            lb: 0,
+            db: 0,
+            bench: 6,
+        }
+    }
+}
+
+impl RosterSettings {
+    pub fn slots(&self, position: &Position) -> i32 {
+        match position {
+            Position::QB => self.qb,
+            Position::RB => self.rb,
+            Position::WR => self.wr,
+            Position::TE => self.te,
+            Position::K => self.k,
+            Position::DST => self.dst,
+            Position::DL => self.dl,
+            Position::LB => self.lb,
+            Position::DB => self.db,
+        }
+    }
+
+    // Flex and superflex slots a position can fill.
+    pub fn flex_slots(&self, position: &Position) -> i32 {
+        match position {
+            Position::QB => self.superflex,
+            Position::RB | Position::WR | Position::TE => self.flex + self.superflex,
+            _ => 0,
+        }
+    }
+
+    pub fn size(&self) -> i32 {
+        self.qb
+            + self.rb
+            + self.wr
+            + self.te
+            + self.flex
+            + self.superflex
+            + self.k
+            + self.dst
+            + self.dl
+            + self.lb
+            + self.db
+            + self.bench
+    }
+}
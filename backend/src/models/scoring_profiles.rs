@@ -0,0 +1,47 @@
+use crate::models::stats::StatsBase;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+// Point value per unit of each stat line field (e.g. `pass_td: 6.0`,
+// `receptions: 0.25`). Fields left out of a request default to 0. DST
+// points/yards allowed are scored per game against the tier brackets.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ScoringRules {
+    pub weights: StatsBase,
+    #[serde(default)]
+    pub points_allowed_tiers: Vec<ScoringTier>,
+    #[serde(default)]
+    pub yards_allowed_tiers: Vec<ScoringTier>,
+}
+
+// One bracket of a tiered stat, e.g. `{ "up_to": 6, "points": 7 }` for 1-6
+// points allowed. Brackets are checked in order; the last one usually leaves
+// `up_to` empty to catch everything above.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringTier {
+    pub up_to: Option<f64>,
+    pub points: f64,
+}
+
+impl TryFrom<serde_json::Value> for ScoringRules {
+    type Error = serde_json::Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub rules: ScoringRules,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Deserialize)]
+pub struct ScoringProfileRequest {
+    pub name: String,
+    pub rules: ScoringRules,
+}
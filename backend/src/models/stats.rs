@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct StatsBase {
     pub pass_cmp: f64,
     pub pass_att: f64,
@@ -43,15 +44,97 @@ pub struct StatsBase {
     pub def_td: f64,
     pub safeties: f64,
     pub special_teams_td: f64,
+    pub points_allowed: f64,
+    pub yards_allowed: f64,
+    pub tackles: f64,
+    pub assists: f64,
+    pub tackles_for_loss: f64,
+    pub qb_hits: f64,
+    pub passes_defended: f64,
+    pub idp_sacks: f64,
+    pub idp_int: f64,
+    pub idp_fumbles_forced: f64,
+    pub idp_fumbles_recovered: f64,
+    pub idp_td: f64,
     pub games: f64,
 }
 
+impl StatsBase {
+    // Sets the field named by a `STATS_BY_POSITION` header. Unknown headers
+    // are ignored.
+    pub fn set(&mut self, field: &str, value: f64) {
+        match field {
+            "pass_cmp" => self.pass_cmp = value,
+            "pass_att" => self.pass_att = value,
+            "pass_cmp_pct" => self.pass_cmp_pct = value,
+            "pass_yds" => self.pass_yds = value,
+            "pass_yds_per_att" => self.pass_yds_per_att = value,
+            "pass_td" => self.pass_td = value,
+            "pass_int" => self.pass_int = value,
+            "pass_sacks" => self.pass_sacks = value,
+            "rush_att" => self.rush_att = value,
+            "rush_yds" => self.rush_yds = value,
+            "rush_yds_per_att" => self.rush_yds_per_att = value,
+            "rush_long" => self.rush_long = value,
+            "rush_20" => self.rush_20 = value,
+            "rush_td" => self.rush_td = value,
+            "fumbles" => self.fumbles = value,
+            "receptions" => self.receptions = value,
+            "rec_tgt" => self.rec_tgt = value,
+            "rec_tgt_pct" => self.rec_tgt_pct = value,
+            "rec_yds" => self.rec_yds = value,
+            "rec_yds_per_rec" => self.rec_yds_per_rec = value,
+            "rec_long" => self.rec_long = value,
+            "rec_20" => self.rec_20 = value,
+            "rec_td" => self.rec_td = value,
+            "field_goals" => self.field_goals = value,
+            "fg_att" => self.fg_att = value,
+            "fg_pct" => self.fg_pct = value,
+            "fg_long" => self.fg_long = value,
+            "fg_1_19" => self.fg_1_19 = value,
+            "fg_20_29" => self.fg_20_29 = value,
+            "fg_30_39" => self.fg_30_39 = value,
+            "fg_40_49" => self.fg_40_49 = value,
+            "fg_50" => self.fg_50 = value,
+            "extra_points" => self.extra_points = value,
+            "xp_att" => self.xp_att = value,
+            "sacks" => self.sacks = value,
+            "int" => self.int = value,
+            "fumbles_recovered" => self.fumbles_recovered = value,
+            "fumbles_forced" => self.fumbles_forced = value,
+            "def_td" => self.def_td = value,
+            "safeties" => self.safeties = value,
+            "special_teams_td" => self.special_teams_td = value,
+            "points_allowed" => self.points_allowed = value,
+            "yards_allowed" => self.yards_allowed = value,
+            "tackles" => self.tackles = value,
+            "assists" => self.assists = value,
+            "tackles_for_loss" => self.tackles_for_loss = value,
+            "qb_hits" => self.qb_hits = value,
+            "passes_defended" => self.passes_defended = value,
+            "idp_sacks" => self.idp_sacks = value,
+            "idp_int" => self.idp_int = value,
+            "idp_fumbles_forced" => self.idp_fumbles_forced = value,
+            "idp_fumbles_recovered" => self.idp_fumbles_recovered = value,
+            "idp_td" => self.idp_td = value,
+            "games" => self.games = value,
+            _ => (),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatsResponse {
+    pub season: Option<i32>,
     #[serde(flatten)]
     pub base: StatsBase,
     pub points: Option<f64>,
     pub points_per_game: Option<f64>,
+    // Only filled in on best-ball boards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spike_weeks: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_ball_points: Option<f64>,
 }
 
 impl From<serde_json::Value> for StatsResponse {
@@ -63,24 +146,12 @@ impl From<serde_json::Value> for StatsResponse {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Stats {
     pub player_id: i32,
+    pub season: i32,
     #[serde(flatten)]
     pub base: StatsBase,
-    pub standard_pts: f64,
-    pub standard_pts_per_game: f64,
-    pub half_ppr_pts: f64,
-    pub half_ppr_pts_per_game: f64,
-    pub ppr_pts: f64,
-    pub ppr_pts_per_game: f64,
 }
 
 impl Stats {
-    pub fn new(player_id: i32) -> Self {
-        Self {
-            player_id,
-            ..Default::default()
-        }
-    }
-
     pub fn update_from(&mut self, other: &Stats) {
         macro_rules! update_max_base {
             ($($field:ident),*) => {
@@ -88,12 +159,6 @@ impl Stats {
             }
         }
 
-        macro_rules! update_max_scoring {
-            ($($field:ident),*) => {
-                $(self.$field = self.$field.max(other.$field);)*
-            }
-        }
-
         update_max_base!(
             pass_cmp,
             pass_att,
@@ -136,16 +201,19 @@ impl Stats {
             def_td,
             safeties,
             special_teams_td,
+            points_allowed,
+            yards_allowed,
+            tackles,
+            assists,
+            tackles_for_loss,
+            qb_hits,
+            passes_defended,
+            idp_sacks,
+            idp_int,
+            idp_fumbles_forced,
+            idp_fumbles_recovered,
+            idp_td,
             games
         );
-
-        update_max_scoring!(
-            standard_pts,
-            standard_pts_per_game,
-            half_ppr_pts,
-            half_ppr_pts_per_game,
-            ppr_pts,
-            ppr_pts_per_game
-        );
     }
 }
@@ -1,12 +1,16 @@
-use crate::models::rankings::ScoringSettings;
+use crate::models::rankings::{LeagueFormat, RankingType, ScoringSettings};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: i32,
     pub username: String,
     pub scoring_settings: ScoringSettings,
+    pub league_format: LeagueFormat,
+    pub ranking_type: RankingType,
+    pub scoring_profile_id: Option<i32>,
+    pub idp: bool,
     pub created_at: OffsetDateTime,
 }
 
@@ -14,9 +18,23 @@ pub struct User {
 pub struct CreateUserRequest {
     pub username: String,
     pub scoring_settings: ScoringSettings,
+    #[serde(default)]
+    pub league_format: LeagueFormat,
+    #[serde(default)]
+    pub ranking_type: RankingType,
+    #[serde(default)]
+    pub idp: bool,
 }
 
+// Picking a preset without a profile clears any active custom profile. The
+// preset still decides which FantasyPros ranking set the board is ordered by.
 #[derive(Deserialize)]
 pub struct UpdateUserRequest {
     pub scoring_settings: ScoringSettings,
+    #[serde(default)]
+    pub scoring_profile_id: Option<i32>,
+    // Left unchanged when omitted.
+    pub league_format: Option<LeagueFormat>,
+    pub ranking_type: Option<RankingType>,
+    pub idp: Option<bool>,
 }
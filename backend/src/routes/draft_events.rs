@@ -0,0 +1,59 @@
+use actix_web::error::ErrorInternalServerError;
+use actix_web::{get, post, web, HttpRequest, HttpResponse, Result};
+
+use crate::database::operations::draft_event_operations;
+use crate::models::draft_events::ReplayQuery;
+use crate::models::draft_rooms::DraftRoomMessage;
+use crate::routes::utils::{draft_error_response, get_user_id};
+use crate::services::draft_room_service;
+
+#[get("/draft-events")]
+pub async fn get_draft_events(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let events = draft_event_operations::get_events(user_id)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get draft events: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    Ok(HttpResponse::Ok().json(events))
+}
+
+#[get("/draft-events/replay")]
+pub async fn replay_draft(
+    query: web::Query<ReplayQuery>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let board = draft_event_operations::replay(user_id, query.event_id)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to replay draft: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    Ok(HttpResponse::Ok().json(board))
+}
+
+#[post("/draft-events/undo")]
+pub async fn undo_draft_event(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let event = draft_event_operations::undo(user_id)
+        .await
+        .map_err(draft_error_response)?;
+    draft_room_service::broadcast(user_id, DraftRoomMessage::BoardChanged);
+
+    Ok(HttpResponse::Ok().json(event))
+}
+
+#[post("/draft-events/redo")]
+pub async fn redo_draft_event(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let event = draft_event_operations::redo(user_id)
+        .await
+        .map_err(draft_error_response)?;
+    draft_room_service::broadcast(user_id, DraftRoomMessage::BoardChanged);
+
+    Ok(HttpResponse::Ok().json(event))
+}
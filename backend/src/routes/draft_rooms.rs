@@ -0,0 +1,53 @@
+use actix_web::{get, rt, web, HttpRequest, HttpResponse, Result};
+use actix_ws::Message;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::models::draft_rooms::DraftRoomQuery;
+use crate::services::draft_room_service;
+
+// Browsers can't set headers on a WebSocket, so the board owner's user id is
+// in the path instead of the X-User-Id header.
+#[get("/draft-rooms/{user_id}/ws")]
+pub async fn join_draft_room(
+    room_id: web::Path<i32>,
+    query: web::Query<DraftRoomQuery>,
+    req: HttpRequest,
+    body: web::Payload,
+) -> Result<HttpResponse> {
+    let room_id = room_id.into_inner();
+    let (response, mut session, mut msg_stream) = actix_ws::handle(&req, body)?;
+    let (connection_id, mut receiver) = draft_room_service::join(room_id, query.into_inner().name);
+
+    rt::spawn(async move {
+        loop {
+            tokio::select! {
+                msg = msg_stream.recv() => match msg {
+                    Some(Ok(Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+                message = receiver.recv() => match message {
+                    Ok(message) => {
+                        let text = serde_json::to_string(&message).unwrap();
+                        if session.text(text).await.is_err() {
+                            break;
+                        }
+                    }
+                    // Missed updates just mean the client should refetch.
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+
+        drop(receiver);
+        draft_room_service::leave(room_id, connection_id);
+        let _ = session.close(None).await;
+    });
+
+    Ok(response)
+}
@@ -1,19 +1,74 @@
+use actix_web::error::ErrorBadRequest;
 use actix_web::error::ErrorInternalServerError;
-use actix_web::{delete, post, web, HttpRequest, HttpResponse, Result};
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Result};
 use serde_json::json;
 
-use crate::database::operations::drafted_player_operations;
-use crate::routes::utils::get_user_id;
+use crate::database::operations::{drafted_player_operations, roster_settings_operations};
+use crate::models::draft_rooms::DraftRoomMessage;
+use crate::models::drafted_players::{DraftRequest, DraftResponse, KeeperRequest};
+use crate::models::roster::RosterSettings;
+use crate::routes::utils::{draft_error_response, get_user_id};
+use crate::services::draft_room_service;
 
 #[post("/drafted_players/{player_id}")]
-pub async fn draft_player(player_id: web::Path<i32>, req: HttpRequest) -> Result<HttpResponse> {
+pub async fn draft_player(
+    player_id: web::Path<i32>,
+    body: web::Bytes,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
     let user_id = get_user_id(&req)?;
-    let drafted_player = drafted_player_operations::draft_player(user_id, player_id.into_inner())
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to draft player: {}", e);
-            ErrorInternalServerError(e)
-        })?;
+    let player_id = player_id.into_inner();
+    // Crossing a player off without a league needs no body at all, but one
+    // that's sent has to parse.
+    let draft_request: DraftRequest = match body.is_empty() {
+        true => DraftRequest::default(),
+        false => serde_json::from_slice(&body)
+            .map_err(|e| ErrorBadRequest(format!("Invalid draft request: {}", e)))?,
+    };
+    let drafted_player =
+        drafted_player_operations::draft_player(user_id, player_id, &draft_request)
+            .await
+            .map_err(draft_error_response)?;
+    draft_room_service::broadcast(
+        user_id,
+        DraftRoomMessage::Drafted {
+            drafted_player: drafted_player.clone(),
+        },
+    );
+
+    let warning = match drafted_player.drafted_by_me {
+        true => drafted_player_operations::roster_warning(user_id, player_id)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to check roster slots: {}", e);
+                ErrorInternalServerError(e)
+            })?,
+        false => None,
+    };
+
+    Ok(HttpResponse::Ok().json(DraftResponse {
+        drafted_player,
+        warning,
+    }))
+}
+
+#[post("/drafted_players/{player_id}/keeper")]
+pub async fn keep_player(
+    player_id: web::Path<i32>,
+    keeper_request: web::Json<KeeperRequest>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let drafted_player =
+        drafted_player_operations::keep_player(user_id, player_id.into_inner(), &keeper_request)
+            .await
+            .map_err(draft_error_response)?;
+    draft_room_service::broadcast(
+        user_id,
+        DraftRoomMessage::Drafted {
+            drafted_player: drafted_player.clone(),
+        },
+    );
 
     Ok(HttpResponse::Ok().json(drafted_player))
 }
@@ -27,6 +82,7 @@ pub async fn reset_board(req: HttpRequest) -> Result<HttpResponse> {
             eprintln!("Failed to reset draft board: {}", e);
             ErrorInternalServerError(e)
         })?;
+    draft_room_service::broadcast(user_id, DraftRoomMessage::Reset);
 
     Ok(HttpResponse::Ok().json(json!({ "cleared": cleared })))
 }
@@ -34,7 +90,8 @@ pub async fn reset_board(req: HttpRequest) -> Result<HttpResponse> {
 #[delete("/drafted_players/{player_id}")]
 pub async fn undraft_player(player_id: web::Path<i32>, req: HttpRequest) -> Result<HttpResponse> {
     let user_id = get_user_id(&req)?;
-    let success = drafted_player_operations::undraft_player(user_id, player_id.into_inner())
+    let player_id = player_id.into_inner();
+    let success = drafted_player_operations::undraft_player(user_id, player_id)
         .await
         .map_err(|e| {
             eprintln!("Failed to undraft player: {}", e);
@@ -42,8 +99,72 @@ pub async fn undraft_player(player_id: web::Path<i32>, req: HttpRequest) -> Resu
         })?;
 
     if success {
+        draft_room_service::broadcast(user_id, DraftRoomMessage::Undrafted { player_id });
         Ok(HttpResponse::Ok().finish())
     } else {
         Ok(HttpResponse::NotFound().finish())
     }
 }
+
+#[get("/roster")]
+pub async fn get_roster(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let roster = drafted_player_operations::get_roster(user_id)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get roster: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    Ok(HttpResponse::Ok().json(roster))
+}
+
+#[get("/roster/settings")]
+pub async fn get_roster_settings(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let roster_settings = roster_settings_operations::get_roster_settings(user_id)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get roster settings: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    Ok(HttpResponse::Ok().json(roster_settings))
+}
+
+#[put("/roster/settings")]
+pub async fn update_roster_settings(
+    roster_settings: web::Json<RosterSettings>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let counts = [
+        roster_settings.qb,
+        roster_settings.rb,
+        roster_settings.wr,
+        roster_settings.te,
+        roster_settings.flex,
+        roster_settings.superflex,
+        roster_settings.k,
+        roster_settings.dst,
+        roster_settings.dl,
+        roster_settings.lb,
+        roster_settings.db,
+        roster_settings.bench,
+    ];
+    if counts.iter().any(|count| *count < 0) || roster_settings.size() == 0 {
+        return Err(ErrorBadRequest(
+            "Slot counts can't be negative and a roster needs at least one slot",
+        ));
+    }
+
+    let roster_settings =
+        roster_settings_operations::update_roster_settings(user_id, &roster_settings)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to update roster settings: {}", e);
+                ErrorInternalServerError(e)
+            })?;
+
+    Ok(HttpResponse::Ok().json(roster_settings))
+}
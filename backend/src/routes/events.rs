@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use actix_web::web::Bytes;
+use actix_web::{get, HttpRequest, HttpResponse, Result};
+use futures::stream;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::routes::utils::get_user_id;
+use crate::services::draft_room_service;
+
+// Comment lines keep proxies from timing out an idle stream.
+const KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+// Server-sent events for the user's board: the same messages the draft room
+// WebSocket sends, one JSON object per `data:` line.
+#[get("/events")]
+pub async fn get_events(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let receiver = draft_room_service::subscribe(user_id);
+
+    let events = stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            let frame = match tokio::time::timeout(KEEP_ALIVE, receiver.recv()).await {
+                Ok(Ok(message)) => {
+                    format!("data: {}\n\n", serde_json::to_string(&message).unwrap())
+                }
+                // Missed updates just mean the client should refetch.
+                Ok(Err(RecvError::Lagged(_))) => continue,
+                Ok(Err(RecvError::Closed)) => return None,
+                Err(_) => ": keep-alive\n\n".to_string(),
+            };
+            return Some((Ok::<_, actix_web::Error>(Bytes::from(frame)), receiver));
+        }
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .streaming(events))
+}
@@ -1,11 +1,29 @@
 use crate::database::operations::fantasy_data_operations;
 use crate::services::fantasy_data_service;
-use actix_web::{error::ErrorInternalServerError, get, post, HttpResponse, Result};
+use actix_web::{error::ErrorInternalServerError, get, post, web, HttpResponse, Result};
+use serde::Deserialize;
 use serde_json::json;
 
+// Each backfilled season is a full stats and game log scrape.
+const MAX_BACKFILL_SEASONS: u32 = 10;
+
+#[derive(Deserialize)]
+pub struct UpdateFantasyDataQuery {
+    // Number of seasons before the latest one to re-scrape stats for.
+    #[serde(default)]
+    pub backfill_seasons: u32,
+}
+
 #[post("/fantasy-data/update")]
-pub async fn update_fantasy_data() -> HttpResponse {
-    match fantasy_data_service::update().await {
+pub async fn update_fantasy_data(query: web::Query<UpdateFantasyDataQuery>) -> HttpResponse {
+    if query.backfill_seasons > MAX_BACKFILL_SEASONS {
+        return HttpResponse::BadRequest().json(format!(
+            "backfill_seasons must be between 0 and {}",
+            MAX_BACKFILL_SEASONS
+        ));
+    }
+
+    match fantasy_data_service::update(query.backfill_seasons).await {
         Ok(_) => HttpResponse::Ok().json("Fantasy data update completed successfully"),
         Err(e) => {
             eprintln!("Fantasy data update failed: {}", e);
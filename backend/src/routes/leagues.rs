@@ -0,0 +1,144 @@
+use actix_web::error::{ErrorBadRequest, ErrorInternalServerError};
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Result};
+
+use crate::constants::{MAX_DRAFT_ROUNDS, MAX_DRAFT_TEAMS};
+use crate::database::operations::league_operations;
+use crate::models::draft_rooms::DraftRoomMessage;
+use crate::models::leagues::{CreateLeagueRequest, PickClockRequest, TradePicksRequest};
+use crate::routes::utils::{draft_error_response, get_user_id};
+use crate::services::draft_room_service;
+
+#[get("/league")]
+pub async fn get_league(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let league = league_operations::get_league(user_id).await.map_err(|e| {
+        eprintln!("Failed to get league: {}", e);
+        ErrorInternalServerError(e)
+    })?;
+
+    match league {
+        Some(league) => Ok(HttpResponse::Ok().json(league)),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[post("/league")]
+pub async fn create_league(
+    create_league_request: web::Json<CreateLeagueRequest>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    if create_league_request.teams.is_empty() || create_league_request.rounds < 1 {
+        return Err(ErrorBadRequest(
+            "A league needs at least one team and one round",
+        ));
+    }
+    if create_league_request.teams.len() > MAX_DRAFT_TEAMS as usize
+        || create_league_request.rounds > MAX_DRAFT_ROUNDS
+    {
+        return Err(ErrorBadRequest(format!(
+            "A league can have at most {} teams and {} rounds",
+            MAX_DRAFT_TEAMS, MAX_DRAFT_ROUNDS
+        )));
+    }
+    // Every roster spot costs at least $1 in an auction.
+    if create_league_request
+        .auction_budget
+        .is_some_and(|budget| budget < create_league_request.rounds)
+    {
+        return Err(ErrorBadRequest(
+            "An auction budget needs at least $1 for every round",
+        ));
+    }
+
+    let league = league_operations::create_league(user_id, &create_league_request)
+        .await
+        .map_err(draft_error_response)?;
+    draft_room_service::broadcast(user_id, DraftRoomMessage::BoardChanged);
+
+    Ok(HttpResponse::Created().json(league))
+}
+
+#[put("/league/pick-clock")]
+pub async fn set_pick_clock(
+    pick_clock_request: web::Json<PickClockRequest>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    if pick_clock_request
+        .pick_seconds
+        .is_some_and(|pick_seconds| pick_seconds < 1)
+    {
+        return Err(ErrorBadRequest("The pick clock needs at least one second"));
+    }
+    // Auctions have no single team on the clock to autopick for.
+    let league = league_operations::get_league(user_id).await.map_err(|e| {
+        eprintln!("Failed to get league: {}", e);
+        ErrorInternalServerError(e)
+    })?;
+    match league {
+        Some(league)
+            if league.league.auction_budget.is_some()
+                && pick_clock_request.pick_seconds.is_some() =>
+        {
+            return Err(ErrorBadRequest("Auction drafts don't have a pick clock"));
+        }
+        Some(_) => (),
+        None => return Ok(HttpResponse::NotFound().finish()),
+    }
+
+    let updated = league_operations::set_pick_clock(user_id, pick_clock_request.pick_seconds)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to set pick clock: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+    if !updated {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+    draft_room_service::broadcast(user_id, DraftRoomMessage::BoardChanged);
+
+    let league = league_operations::get_league(user_id).await.map_err(|e| {
+        eprintln!("Failed to get league: {}", e);
+        ErrorInternalServerError(e)
+    })?;
+
+    match league {
+        Some(league) => Ok(HttpResponse::Ok().json(league)),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[post("/league/trades")]
+pub async fn trade_picks(
+    trade_request: web::Json<TradePicksRequest>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let picks = league_operations::trade_picks(user_id, &trade_request)
+        .await
+        .map_err(draft_error_response)?;
+
+    match picks {
+        Some(picks) => {
+            draft_room_service::broadcast(user_id, DraftRoomMessage::BoardChanged);
+            Ok(HttpResponse::Ok().json(picks))
+        }
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[delete("/league")]
+pub async fn delete_league(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let deleted = league_operations::delete_league(user_id)
+        .await
+        .map_err(draft_error_response)?;
+
+    if deleted {
+        draft_room_service::broadcast(user_id, DraftRoomMessage::BoardChanged);
+        Ok(HttpResponse::Ok().finish())
+    } else {
+        Ok(HttpResponse::NotFound().finish())
+    }
+}
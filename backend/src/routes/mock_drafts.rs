@@ -0,0 +1,92 @@
+use actix_web::error::{ErrorBadRequest, ErrorInternalServerError};
+use actix_web::{delete, get, post, web, HttpRequest, HttpResponse, Result};
+
+use crate::constants::{MAX_DRAFT_ROUNDS, MAX_DRAFT_TEAMS};
+use crate::database::operations::mock_draft_operations;
+use crate::models::mock_drafts::CreateMockDraftRequest;
+use crate::routes::utils::{draft_error_response, get_user_id};
+
+#[post("/mock-drafts")]
+pub async fn create_mock_draft(
+    create_mock_draft_request: web::Json<CreateMockDraftRequest>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let request = create_mock_draft_request.into_inner();
+    if request.team_count < 2
+        || request.rounds < 1
+        || !(1..=request.team_count).contains(&request.draft_position)
+    {
+        return Err(ErrorBadRequest(
+            "A mock needs at least two teams, one round and a draft position within the teams",
+        ));
+    }
+    if request.team_count > MAX_DRAFT_TEAMS || request.rounds > MAX_DRAFT_ROUNDS {
+        return Err(ErrorBadRequest(format!(
+            "A mock can have at most {} teams and {} rounds",
+            MAX_DRAFT_TEAMS, MAX_DRAFT_ROUNDS
+        )));
+    }
+
+    let mock_draft = mock_draft_operations::create_mock_draft(user_id, &request)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to create mock draft: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    Ok(HttpResponse::Created().json(mock_draft))
+}
+
+#[get("/mock-drafts/{mock_draft_id}")]
+pub async fn get_mock_draft(
+    mock_draft_id: web::Path<i32>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let mock_draft = mock_draft_operations::get_mock_draft(user_id, mock_draft_id.into_inner())
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get mock draft: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    match mock_draft {
+        Some(mock_draft) => Ok(HttpResponse::Ok().json(mock_draft)),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[post("/mock-drafts/{mock_draft_id}/picks/{player_id}")]
+pub async fn make_mock_pick(path: web::Path<(i32, i32)>, req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let (mock_draft_id, player_id) = path.into_inner();
+    let mock_draft = mock_draft_operations::make_pick(user_id, mock_draft_id, player_id)
+        .await
+        .map_err(draft_error_response)?;
+
+    match mock_draft {
+        Some(mock_draft) => Ok(HttpResponse::Ok().json(mock_draft)),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[delete("/mock-drafts/{mock_draft_id}")]
+pub async fn delete_mock_draft(
+    mock_draft_id: web::Path<i32>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let deleted = mock_draft_operations::delete_mock_draft(user_id, mock_draft_id.into_inner())
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to delete mock draft: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    if deleted {
+        Ok(HttpResponse::Ok().finish())
+    } else {
+        Ok(HttpResponse::NotFound().finish())
+    }
+}
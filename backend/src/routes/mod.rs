@@ -1,5 +1,12 @@
+pub mod draft_events;
+pub mod draft_rooms;
 pub mod drafted_players;
+pub mod events;
 pub mod fantasy_data;
+pub mod leagues;
+pub mod mock_drafts;
 pub mod players;
+pub mod recommendations;
+pub mod scoring_profiles;
 pub mod users;
 mod utils;
@@ -1,16 +1,62 @@
-use actix_web::error::ErrorInternalServerError;
-use actix_web::{get, HttpRequest, HttpResponse, Result};
+use actix_web::error::{ErrorBadRequest, ErrorInternalServerError};
+use actix_web::{get, web, HttpRequest, HttpResponse, Result};
 
 use crate::database::operations::player_operations;
+use crate::models::game_logs::GameLogsQuery;
+use crate::models::players::PlayersQuery;
 use crate::routes::utils::get_user_id;
+use crate::services::board_service;
 
 #[get("/players")]
-pub async fn get_players(req: HttpRequest) -> Result<HttpResponse> {
+pub async fn get_players(
+    query: web::Query<PlayersQuery>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
     let user_id = get_user_id(&req)?;
-    let players = player_operations::get_players(user_id).await.map_err(|e| {
-        eprintln!("Failed to get players: {}", e);
-        ErrorInternalServerError(e)
-    })?;
+    query.manual_baselines().map_err(ErrorBadRequest)?;
+    if query
+        .tier_sensitivity
+        .is_some_and(|sensitivity| sensitivity <= 0.0)
+    {
+        return Err(ErrorBadRequest("Tier sensitivity must be positive"));
+    }
+    let players = board_service::build_board(user_id, &query)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get players: {}", e);
+            ErrorInternalServerError(e)
+        })?;
 
     Ok(HttpResponse::Ok().json(players))
 }
+
+#[get("/players/{player_id}/stats")]
+pub async fn get_player_stats(player_id: web::Path<i32>, req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let stats = player_operations::get_player_stats(user_id, player_id.into_inner())
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get player stats: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    Ok(HttpResponse::Ok().json(stats))
+}
+
+#[get("/players/{player_id}/game-logs")]
+pub async fn get_player_game_logs(
+    player_id: web::Path<i32>,
+    query: web::Query<GameLogsQuery>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let game_logs =
+        player_operations::get_player_game_logs(user_id, player_id.into_inner(), query.season)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to get player game logs: {}", e);
+                ErrorInternalServerError(e)
+            })?;
+
+    Ok(HttpResponse::Ok().json(game_logs))
+}
@@ -0,0 +1,25 @@
+use actix_web::error::ErrorInternalServerError;
+use actix_web::{get, web, HttpRequest, HttpResponse, Result};
+
+use crate::models::recommendations::RecommendationsQuery;
+use crate::routes::utils::get_user_id;
+use crate::services::board_service;
+
+const DEFAULT_LIMIT: usize = 10;
+
+#[get("/recommendations")]
+pub async fn get_recommendations(
+    query: web::Query<RecommendationsQuery>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let recommendations =
+        board_service::get_recommendations(user_id, query.limit.unwrap_or(DEFAULT_LIMIT))
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to get recommendations: {}", e);
+                ErrorInternalServerError(e)
+            })?;
+
+    Ok(HttpResponse::Ok().json(recommendations))
+}
@@ -0,0 +1,120 @@
+use actix_web::error::ErrorInternalServerError;
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Result};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+
+use crate::database::operations::scoring_profile_operations;
+use crate::models::rankings::ScoringSettings;
+use crate::models::scoring_profiles::ScoringProfileRequest;
+use crate::routes::utils::get_user_id;
+use crate::services::scoring_service;
+
+#[get("/scoring-profiles/presets")]
+pub async fn get_scoring_presets() -> Result<HttpResponse> {
+    let presets: HashMap<_, _> = ScoringSettings::iter()
+        .map(|scoring_settings| {
+            let rules = scoring_service::preset(&scoring_settings);
+            (scoring_settings, rules)
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(presets))
+}
+
+#[get("/scoring-profiles")]
+pub async fn get_scoring_profiles(req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let profiles = scoring_profile_operations::get_scoring_profiles(user_id)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get scoring profiles: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    Ok(HttpResponse::Ok().json(profiles))
+}
+
+#[get("/scoring-profiles/{profile_id}")]
+pub async fn get_scoring_profile(
+    profile_id: web::Path<i32>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let profile = scoring_profile_operations::get_scoring_profile(user_id, profile_id.into_inner())
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get scoring profile: {}", e);
+            ErrorInternalServerError(e)
+        })?;
+
+    match profile {
+        Some(profile) => Ok(HttpResponse::Ok().json(profile)),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[post("/scoring-profiles")]
+pub async fn create_scoring_profile(
+    profile_request: web::Json<ScoringProfileRequest>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let profile = scoring_profile_operations::create_scoring_profile(
+        user_id,
+        &profile_request.name,
+        &profile_request.rules,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Failed to create scoring profile: {}", e);
+        ErrorInternalServerError(e)
+    })?;
+
+    Ok(HttpResponse::Created().json(profile))
+}
+
+#[put("/scoring-profiles/{profile_id}")]
+pub async fn update_scoring_profile(
+    profile_id: web::Path<i32>,
+    profile_request: web::Json<ScoringProfileRequest>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let profile = scoring_profile_operations::update_scoring_profile(
+        user_id,
+        profile_id.into_inner(),
+        &profile_request.name,
+        &profile_request.rules,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Failed to update scoring profile: {}", e);
+        ErrorInternalServerError(e)
+    })?;
+
+    match profile {
+        Some(profile) => Ok(HttpResponse::Ok().json(profile)),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[delete("/scoring-profiles/{profile_id}")]
+pub async fn delete_scoring_profile(
+    profile_id: web::Path<i32>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = get_user_id(&req)?;
+    let success =
+        scoring_profile_operations::delete_scoring_profile(user_id, profile_id.into_inner())
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to delete scoring profile: {}", e);
+                ErrorInternalServerError(e)
+            })?;
+
+    if success {
+        Ok(HttpResponse::Ok().finish())
+    } else {
+        Ok(HttpResponse::NotFound().finish())
+    }
+}
@@ -1,8 +1,10 @@
-use actix_web::error::ErrorInternalServerError;
+use actix_web::error::{ErrorBadRequest, ErrorInternalServerError};
 use actix_web::{get, post, put, web, HttpResponse, Result};
 
-use crate::database::operations::user_operations;
+use crate::database::operations::{scoring_profile_operations, user_operations};
+use crate::models::draft_rooms::DraftRoomMessage;
 use crate::models::users::{CreateUserRequest, UpdateUserRequest};
+use crate::services::draft_room_service;
 
 #[get("/users/{username}")]
 pub async fn get_user(username: web::Path<String>) -> Result<HttpResponse> {
@@ -21,15 +23,12 @@ pub async fn get_user(username: web::Path<String>) -> Result<HttpResponse> {
 pub async fn create_user(
     create_user_request: web::Json<CreateUserRequest>,
 ) -> Result<HttpResponse> {
-    let new_user = user_operations::create_user(
-        &create_user_request.username,
-        &create_user_request.scoring_settings,
-    )
-    .await
-    .map_err(|e| {
-        eprintln!("Failed to create user: {}", e);
-        ErrorInternalServerError(e)
-    })?;
+    let new_user = user_operations::create_user(&create_user_request)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to create user: {}", e);
+            ErrorInternalServerError(e)
+        })?;
 
     Ok(HttpResponse::Created().json(new_user))
 }
@@ -39,16 +38,36 @@ pub async fn update_user(
     username: web::Path<String>,
     update_user_request: web::Json<UpdateUserRequest>,
 ) -> Result<HttpResponse> {
-    let updated_user =
-        user_operations::update_user(&username, &update_user_request.scoring_settings)
+    if let Some(profile_id) = update_user_request.scoring_profile_id {
+        let owned = scoring_profile_operations::owns_scoring_profile(&username, profile_id)
             .await
             .map_err(|e| {
-                eprintln!("Failed to update user: {}", e);
+                eprintln!("Failed to check scoring profile: {}", e);
                 ErrorInternalServerError(e)
             })?;
+        if !owned {
+            return Err(ErrorBadRequest(format!(
+                "Unknown scoring profile {}",
+                profile_id
+            )));
+        }
+    }
+
+    let updated_user = user_operations::update_user(&username, &update_user_request)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to update user: {}", e);
+            ErrorInternalServerError(e)
+        })?;
 
     match updated_user {
-        Some(user) => Ok(HttpResponse::Ok().json(user)),
+        Some(user) => {
+            draft_room_service::broadcast(
+                user.id,
+                DraftRoomMessage::UserUpdated { user: user.clone() },
+            );
+            Ok(HttpResponse::Ok().json(user))
+        }
         None => Ok(HttpResponse::NotFound().finish()),
     }
 }
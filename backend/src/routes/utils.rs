@@ -1,7 +1,8 @@
-use actix_web::error::ErrorBadRequest;
+use actix_web::error::{ErrorBadRequest, ErrorConflict, ErrorInternalServerError};
 use actix_web::{HttpRequest, Result};
 
 use crate::constants::HEADER_USER_ID;
+use crate::services::draft_service::DraftError;
 
 pub fn get_user_id(req: &HttpRequest) -> Result<i32> {
     req.headers()
@@ -12,3 +13,31 @@ pub fn get_user_id(req: &HttpRequest) -> Result<i32> {
         .parse::<i32>()
         .map_err(|_| ErrorBadRequest(format!("Invalid {} header value", HEADER_USER_ID)))
 }
+
+// Bad requests are 400s and picks the draft state doesn't allow are 409s.
+pub fn draft_error_response(e: DraftError) -> actix_web::Error {
+    match e {
+        DraftError::MissingWinningBid
+        | DraftError::UnknownTeam
+        | DraftError::MissingKeeperTeam
+        | DraftError::InvalidKeeperRound
+        | DraftError::NoPickSlots
+        | DraftError::EmptyTrade => ErrorBadRequest(e),
+        DraftError::OutOfTurn { .. }
+        | DraftError::DraftComplete
+        | DraftError::RosterFull
+        | DraftError::InvalidBid { .. }
+        | DraftError::PickTaken
+        | DraftError::PlayerUnavailable
+        | DraftError::StalePick
+        | DraftError::NothingToUndo
+        | DraftError::NothingToRedo
+        | DraftError::NoPickInRound
+        | DraftError::PickNotOwned { .. }
+        | DraftError::LeagueHasPicks => ErrorConflict(e),
+        DraftError::Database(_) => {
+            eprintln!("Failed to make pick: {}", e);
+            ErrorInternalServerError(e)
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use anyhow::Result;
+use futures::stream;
+use futures::stream::StreamExt;
+use reqwest::Client;
+use scraper::{Html, Selector};
+
+use crate::constants::GAME_LOG_STATS_BY_POSITION;
+use crate::models::game_logs::GameLog;
+use crate::models::players::{PlayerTask, Position};
+use crate::models::stats::StatsBase;
+use crate::scrapers::stats_scraper::parse_stats_row;
+
+pub struct GameLogsScraper {
+    client: Client,
+    url: String,
+    position: Position,
+}
+
+impl GameLogsScraper {
+    pub fn new(url: &str, position: Position) -> Self {
+        GameLogsScraper {
+            client: Client::new(),
+            url: url.to_string(),
+            position,
+        }
+    }
+
+    // Returns (week, stat line) for every game the player appeared in.
+    pub async fn scrape(&self) -> Result<Vec<(i32, StatsBase)>> {
+        let headers = match GAME_LOG_STATS_BY_POSITION
+            .get(self.position.to_string().to_lowercase().as_str())
+        {
+            Some(headers) => headers,
+            None => return Ok(Vec::new()),
+        };
+
+        let response = self.client.get(&self.url).send().await?;
+        let body = response.text().await?;
+        let html = Html::parse_document(&body);
+
+        let row_selector = Selector::parse("table.table-bordered tbody tr").unwrap();
+        let cell_selector = Selector::parse("td").unwrap();
+        let mut weeks = Vec::new();
+
+        for row in html.select(&row_selector) {
+            let cells: Vec<String> = row
+                .select(&cell_selector)
+                .map(|cell| cell.text().collect::<String>().trim().to_string())
+                .collect();
+            let week = match cells.first().and_then(|week| week.parse::<i32>().ok()) {
+                Some(week) => week,
+                None => continue,
+            };
+
+            // Bye weeks and games the player sat out render as dashes.
+            let played = cells
+                .iter()
+                .skip(3)
+                .any(|cell| !cell.is_empty() && cell != "-");
+            if cells.get(1).is_some_and(|opponent| opponent == "BYE") || !played {
+                continue;
+            }
+
+            let mut base = parse_stats_row(&row, headers, 3);
+            base.games = 1.0;
+            weeks.push((week, base));
+        }
+
+        Ok(weeks)
+    }
+
+    pub async fn process_tasks(tasks: Vec<PlayerTask>, season: i32) -> Result<Vec<GameLog>> {
+        let results: Vec<_> = stream::iter(tasks)
+            .map(|task| {
+                tokio::spawn(async move {
+                    let url = format!(
+                        "{}?season={}",
+                        task.identity.bio_url.replace("/schedule/", "/games/"),
+                        season
+                    );
+                    let game_logs_scraper = GameLogsScraper::new(&url, task.position);
+                    let weeks = game_logs_scraper.scrape().await?;
+
+                    Ok::<_, anyhow::Error>(
+                        weeks
+                            .into_iter()
+                            .map(|(week, base)| GameLog {
+                                player_id: task.identity.id,
+                                season,
+                                week,
+                                base,
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                })
+            })
+            .buffer_unordered(5)
+            .collect()
+            .await;
+
+        let mut game_logs = Vec::new();
+        for result in results {
+            match result {
+                Ok(Ok(player_game_logs)) => game_logs.extend(player_game_logs),
+                Ok(Err(e)) => println!("Error fetching game logs: {}", e),
+                Err(e) => println!("Task join error: {}", e),
+            }
+        }
+
+        Ok(game_logs)
+    }
+}
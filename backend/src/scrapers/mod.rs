@@ -1,3 +1,5 @@
+pub mod game_logs_scraper;
 pub mod players_scraper;
+pub mod projections_scraper;
 pub mod rankings_scraper;
 pub mod stats_scraper;
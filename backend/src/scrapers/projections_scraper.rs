@@ -0,0 +1,57 @@
+use anyhow::Result;
+use headless_chrome::Tab;
+use scraper::{Html, Selector};
+
+use crate::constants::{PROJECTED_GAMES, PROJECTIONS_BY_POSITION};
+use crate::models::projections::Projections;
+use crate::scrapers::stats_scraper::{get_player_id, parse_stats_row, StatsScraper};
+
+pub struct ProjectionsScraper<'a> {
+    tab: &'a Tab,
+}
+
+impl<'a> ProjectionsScraper<'a> {
+    pub fn new(tab: &'a Tab) -> Self {
+        ProjectionsScraper { tab }
+    }
+
+    fn build_url(&self, position: &str) -> String {
+        [
+            "https://www.fantasypros.com/nfl/projections/",
+            position,
+            ".php?week=draft",
+        ]
+        .concat()
+    }
+
+    pub async fn scrape(&self) -> Result<Vec<Projections>> {
+        // Projection pages share the stats pages' `table#data` layout, so the
+        // stats scraper's loader (retries, consent banner) is reused as-is.
+        let table_loader = StatsScraper::new(self.tab);
+        let mut projections = Vec::new();
+
+        for (position, headers) in PROJECTIONS_BY_POSITION.iter() {
+            let url = self.build_url(position);
+            let table_html = table_loader.load_stats_table(&url)?;
+            let html = Html::parse_document(&table_html);
+
+            let table_selector = Selector::parse("table#data tbody").unwrap();
+            let row_selector = Selector::parse("tr").unwrap();
+
+            if let Some(table) = html.select(&table_selector).next() {
+                for row in table.select(&row_selector) {
+                    let player_id = match get_player_id(&row) {
+                        Some(id) => id,
+                        None => continue,
+                    };
+                    let mut base = parse_stats_row(&row, headers, 1);
+                    base.games = PROJECTED_GAMES;
+
+                    projections.push(Projections { player_id, base });
+                }
+            }
+        }
+
+        Ok(projections)
+    }
+}
@@ -6,7 +6,7 @@ use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 use crate::models::players::{PlayerIdentity, PlayerTask, Position, Team};
-use crate::models::rankings::{Rankings, RankingsBase, ScoringSettings};
+use crate::models::rankings::{LeagueFormat, RankingType, Rankings, RankingsBase, ScoringSettings};
 
 pub struct RankingsScraper<'a> {
     tab: &'a Tab,
@@ -17,39 +17,167 @@ impl<'a> RankingsScraper<'a> {
         Self { tab }
     }
 
-    fn get_urls() -> std::collections::HashMap<ScoringSettings, &'static str> {
-        std::collections::HashMap::from([
+    fn get_urls(
+    ) -> std::collections::HashMap<(ScoringSettings, LeagueFormat, RankingType), &'static str> {
+        let mut urls = std::collections::HashMap::from([
             (
-                ScoringSettings::Standard,
+                (
+                    ScoringSettings::Standard,
+                    LeagueFormat::OneQB,
+                    RankingType::Redraft,
+                ),
                 "https://www.fantasypros.com/nfl/rankings/consensus-cheatsheets.php",
             ),
             (
-                ScoringSettings::Half,
+                (
+                    ScoringSettings::Half,
+                    LeagueFormat::OneQB,
+                    RankingType::Redraft,
+                ),
                 "https://www.fantasypros.com/nfl/rankings/half-point-ppr-cheatsheets.php",
             ),
             (
-                ScoringSettings::PPR,
+                (
+                    ScoringSettings::PPR,
+                    LeagueFormat::OneQB,
+                    RankingType::Redraft,
+                ),
                 "https://www.fantasypros.com/nfl/rankings/ppr-cheatsheets.php",
             ),
-        ])
+            (
+                (
+                    ScoringSettings::Standard,
+                    LeagueFormat::Superflex,
+                    RankingType::Redraft,
+                ),
+                "https://www.fantasypros.com/nfl/rankings/superflex-cheatsheets.php",
+            ),
+            (
+                (
+                    ScoringSettings::Half,
+                    LeagueFormat::Superflex,
+                    RankingType::Redraft,
+                ),
+                "https://www.fantasypros.com/nfl/rankings/half-point-ppr-superflex-cheatsheets.php",
+            ),
+            (
+                (
+                    ScoringSettings::PPR,
+                    LeagueFormat::Superflex,
+                    RankingType::Redraft,
+                ),
+                "https://www.fantasypros.com/nfl/rankings/ppr-superflex-cheatsheets.php",
+            ),
+        ]);
+
+        // Dynasty, rookie and best-ball sheets aren't split by scoring (and
+        // the rookie and best-ball sheets not by format either), so each is
+        // scraped once under the Standard/1QB key and copied to the other
+        // presets when it's saved.
+        urls.insert(
+            (
+                ScoringSettings::Standard,
+                LeagueFormat::OneQB,
+                RankingType::Dynasty,
+            ),
+            "https://www.fantasypros.com/nfl/rankings/dynasty-overall.php",
+        );
+        urls.insert(
+            (
+                ScoringSettings::Standard,
+                LeagueFormat::Superflex,
+                RankingType::Dynasty,
+            ),
+            "https://www.fantasypros.com/nfl/rankings/dynasty-superflex.php",
+        );
+        urls.insert(
+            (
+                ScoringSettings::Standard,
+                LeagueFormat::OneQB,
+                RankingType::Rookie,
+            ),
+            "https://www.fantasypros.com/nfl/rankings/rookies.php",
+        );
+        urls.insert(
+            (
+                ScoringSettings::Standard,
+                LeagueFormat::OneQB,
+                RankingType::BestBall,
+            ),
+            "https://www.fantasypros.com/nfl/rankings/best-ball-cheatsheets.php",
+        );
+
+        urls
     }
 
+    // FantasyPros publishes a single IDP sheet rather than one per scoring.
+    const IDP_URL: &'static str = "https://www.fantasypros.com/nfl/rankings/idp-cheatsheets.php";
+
     pub async fn scrape(&self) -> Result<(Vec<Rankings>, Vec<PlayerTask>)> {
         let mut ranking_tables = Vec::new();
-
-        for (scoring_settings, url) in Self::get_urls() {
-            let table_html = self.scrape_ranking_table(url)?;
-            ranking_tables.push((table_html, scoring_settings));
+        let mut tables_by_url: std::collections::HashMap<&str, String> =
+            std::collections::HashMap::new();
+
+        for (ranking_set, url) in Self::get_urls() {
+            let table_html = match tables_by_url.get(url) {
+                Some(table_html) => table_html.clone(),
+                None => {
+                    let table_html = self.scrape_ranking_table(url)?;
+                    tables_by_url.insert(url, table_html.clone());
+                    table_html
+                }
+            };
+            ranking_tables.push((table_html, ranking_set));
         }
 
         let mut seen_players = std::collections::HashSet::new();
         let mut all_rankings = Vec::new();
         let mut all_player_tasks = Vec::new();
 
-        for (ranking_table, scoring_settings) in ranking_tables {
+        for (ranking_table, (scoring_settings, league_format, ranking_type)) in ranking_tables {
             let (rankings, player_tasks) = self
-                .parse_ranking_table(&ranking_table, &mut seen_players, scoring_settings)
+                .parse_ranking_table(
+                    &ranking_table,
+                    &mut seen_players,
+                    scoring_settings,
+                    league_format,
+                    ranking_type,
+                )
+                .await?;
+            all_rankings.extend(rankings);
+            all_player_tasks.extend(player_tasks);
+        }
+
+        // The IDP sheet ranks defenders only among themselves, so on the
+        // combined board they're slotted in after every offensive player. It's
+        // a redraft sheet, so dynasty and rookie boards don't get it.
+        let idp_table = self.scrape_ranking_table(Self::IDP_URL)?;
+        for (scoring_settings, league_format, ranking_type) in Self::get_urls()
+            .into_keys()
+            .filter(|(_, _, ranking_type)| *ranking_type == RankingType::Redraft)
+        {
+            let offset = all_rankings
+                .iter()
+                .filter(|ranking| {
+                    ranking.scoring_settings == scoring_settings
+                        && ranking.league_format == league_format
+                        && ranking.ranking_type == ranking_type
+                })
+                .map(|ranking| ranking.base.overall)
+                .max()
+                .unwrap_or(0);
+            let (mut rankings, player_tasks) = self
+                .parse_ranking_table(
+                    &idp_table,
+                    &mut seen_players,
+                    scoring_settings,
+                    league_format,
+                    ranking_type,
+                )
                 .await?;
+            for ranking in &mut rankings {
+                ranking.base.overall += offset;
+            }
             all_rankings.extend(rankings);
             all_player_tasks.extend(player_tasks);
         }
@@ -214,6 +342,8 @@ impl<'a> RankingsScraper<'a> {
         table_html: &str,
         seen_players: &mut std::collections::HashSet<i32>,
         scoring_settings: ScoringSettings,
+        league_format: LeagueFormat,
+        ranking_type: RankingType,
     ) -> Result<(Vec<Rankings>, Vec<PlayerTask>)> {
         let document = Html::parse_document(table_html);
         let row_selector = Selector::parse("tbody tr.player-row").unwrap();
@@ -237,6 +367,8 @@ impl<'a> RankingsScraper<'a> {
             rankings.push(Rankings {
                 player_id: player_identity.id,
                 scoring_settings: scoring_settings.clone(),
+                league_format: league_format.clone(),
+                ranking_type: ranking_type.clone(),
                 base: RankingsBase {
                     overall: overall_ranking,
                     position: position_ranking,
@@ -244,6 +376,8 @@ impl<'a> RankingsScraper<'a> {
                     worst: worst_ranking,
                     average: average_ranking,
                     standard_deviation: standard_deviation_ranking,
+                    tier: None,
+                    overall_tier: None,
                 },
             });
 
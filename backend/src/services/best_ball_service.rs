@@ -0,0 +1,53 @@
+use round::round;
+use std::collections::HashMap;
+
+use crate::models::players::{PlayerResponse, Position};
+
+// A week counts as a spike week when it lands in the top fifth of every
+// weekly line scored at that position over the season.
+const SPIKE_WEEK_PERCENTILE: f64 = 0.8;
+
+// Best ball only ever keeps a player's best weeks, so instead of season totals
+// the board values how often a player spiked and what those weeks were worth.
+// `weekly_points` maps player id to that player's scored weekly lines.
+pub fn apply_spike_weeks(players: &mut [PlayerResponse], weekly_points: &HashMap<i32, Vec<f64>>) {
+    let mut points_by_position: HashMap<Position, Vec<f64>> = HashMap::new();
+    for player in players.iter() {
+        if let Some(weeks) = weekly_points
+            .get(&player.id)
+            .filter(|weeks| !weeks.is_empty())
+        {
+            points_by_position
+                .entry(player.position.clone())
+                .or_default()
+                .extend(weeks);
+        }
+    }
+
+    let thresholds: HashMap<Position, f64> = points_by_position
+        .into_iter()
+        .map(|(position, mut points)| {
+            points.sort_by(f64::total_cmp);
+            let index =
+                ((points.len() as f64 * SPIKE_WEEK_PERCENTILE) as usize).min(points.len() - 1);
+            (position, points[index])
+        })
+        .collect();
+
+    for player in players.iter_mut() {
+        let (Some(weeks), Some(threshold)) = (
+            weekly_points.get(&player.id),
+            thresholds.get(&player.position),
+        ) else {
+            continue;
+        };
+
+        let spike_weeks: Vec<f64> = weeks
+            .iter()
+            .copied()
+            .filter(|points| points >= threshold)
+            .collect();
+        player.stats.spike_weeks = Some(spike_weeks.len() as i32);
+        player.stats.best_ball_points = Some(round(spike_weeks.iter().sum(), 1));
+    }
+}
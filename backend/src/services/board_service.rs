@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use sqlx::Error;
+
+use crate::database::operations::{
+    drafted_player_operations, league_operations, player_operations, roster_settings_operations,
+    scoring_profile_operations, user_operations,
+};
+use crate::models::players::{PlayerResponse, PlayersQuery};
+use crate::models::rankings::RankingType;
+use crate::models::recommendations::Recommendation;
+use crate::models::roster::RosterPlayer;
+use crate::services::recommendation_service::{self, RecommendationSettings};
+use crate::services::valuation_service::{self, AuctionPool, ValuationSettings};
+use crate::services::vbd_service::{self, VbdSettings};
+use crate::services::{best_ball_service, draft_service, scoring_service, tier_service};
+
+// The user's draft board: their ranking set with stats and projections scored
+// by their active rules, plus spike weeks (best ball only), keeper and auction
+// values, tiers and VBD. Empty if the user doesn't exist.
+pub async fn build_board(user_id: i32, query: &PlayersQuery) -> Result<Vec<PlayerResponse>, Error> {
+    let Some(user) = user_operations::get_user_by_id(user_id).await? else {
+        return Ok(Vec::new());
+    };
+    let rules = scoring_profile_operations::get_scoring_rules(&user).await?;
+
+    let mut players = player_operations::get_players(user_id, query.season).await?;
+    for player in &mut players {
+        scoring_service::apply_scoring(&mut player.stats, &player.position, &rules);
+        scoring_service::apply_scoring(&mut player.projections, &player.position, &rules);
+    }
+
+    if user.ranking_type == RankingType::BestBall {
+        let weekly_stats = player_operations::get_weekly_stats(query.season).await?;
+        let weekly_points: HashMap<i32, Vec<f64>> = players
+            .iter()
+            .filter_map(|player| {
+                let weeks = weekly_stats.get(&player.id)?;
+                let points = weeks
+                    .iter()
+                    .map(|week| {
+                        scoring_service::calculate_points(&week.base, &player.position, &rules)
+                    })
+                    .collect();
+                Some((player.id, points))
+            })
+            .collect();
+        best_ball_service::apply_spike_weeks(&mut players, &weekly_points);
+    }
+
+    let league = league_operations::get_league(user_id).await?;
+    let roster = roster_settings_operations::get_roster_settings(user_id).await?;
+    let team_count = query
+        .teams
+        .or_else(|| Some(league.as_ref()?.teams.len() as i32))
+        .unwrap_or(valuation_service::DEFAULT_TEAM_COUNT);
+
+    for player in &mut players {
+        player.keeper_value = player.keeper_round.map(|keeper_round| {
+            draft_service::keeper_value(keeper_round, player.rankings.overall, team_count)
+        });
+    }
+
+    let settings = ValuationSettings {
+        budget: query
+            .budget
+            .or_else(|| league.as_ref()?.league.auction_budget)
+            .unwrap_or(valuation_service::DEFAULT_BUDGET),
+        team_count,
+        roster: &roster,
+        auction: league
+            .as_ref()
+            .and_then(|league| league.budgets.as_ref())
+            .map(|budgets| AuctionPool {
+                remaining_budget: budgets.iter().map(|budget| budget.remaining_budget).sum(),
+                open_slots: budgets.iter().map(|budget| budget.open_slots).sum(),
+            }),
+    };
+    valuation_service::apply_auction_values(&mut players, &settings);
+
+    tier_service::apply_tiers(
+        &mut players,
+        query
+            .tier_sensitivity
+            .unwrap_or(tier_service::DEFAULT_TIER_SENSITIVITY),
+    );
+
+    let manual_baselines = query.manual_baselines().unwrap_or_default();
+    vbd_service::apply_vbd(
+        &mut players,
+        &VbdSettings {
+            team_count,
+            roster: &roster,
+            method: query.baseline,
+            source: query.vbd_source,
+            manual_baselines: &manual_baselines,
+        },
+    );
+
+    Ok(players)
+}
+
+// Best available players for the next pick. In a league draft that's the
+// team on the clock's pick; otherwise a full round is assumed between my
+// picks.
+pub async fn get_recommendations(user_id: i32, limit: usize) -> Result<Vec<Recommendation>, Error> {
+    let players = build_board(user_id, &PlayersQuery::default()).await?;
+    let roster = roster_settings_operations::get_roster_settings(user_id).await?;
+    let my_players: Vec<RosterPlayer> = drafted_player_operations::get_roster(user_id)
+        .await?
+        .positions
+        .into_iter()
+        .flat_map(|group| group.players)
+        .collect();
+
+    let league = league_operations::get_league(user_id).await?;
+    let picks_until_next = match &league {
+        Some(league) => match &league.on_the_clock {
+            Some(slot) if !league.picks.is_empty() => {
+                draft_service::picks_until_next(slot, &league.picks).unwrap_or(0)
+            }
+            _ => league.teams.len() as i32 - 1,
+        },
+        None => valuation_service::DEFAULT_TEAM_COUNT - 1,
+    };
+
+    Ok(recommendation_service::recommend(
+        &players,
+        &RecommendationSettings {
+            roster: &roster,
+            my_players: &my_players,
+            picks_until_next,
+            limit,
+        },
+    ))
+}
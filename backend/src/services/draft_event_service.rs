@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use crate::models::draft_events::{DraftEvent, DraftEventType};
+use crate::models::drafted_players::DraftedPlayer;
+
+// Walks the log and returns the (undo, redo) stacks of event ids. A new draft,
+// undraft or reset clears anything left to redo.
+fn stacks(events: &[DraftEvent]) -> (Vec<i32>, Vec<i32>) {
+    let mut undo = Vec::new();
+    let mut redo = Vec::new();
+    for event in events {
+        match event.event_type {
+            DraftEventType::Draft | DraftEventType::Undraft | DraftEventType::Reset => {
+                undo.push(event.id);
+                redo.clear();
+            }
+            DraftEventType::Undo => redo.extend(undo.pop()),
+            DraftEventType::Redo => undo.extend(redo.pop()),
+        }
+    }
+    (undo, redo)
+}
+
+pub fn undo_target(events: &[DraftEvent]) -> Option<i32> {
+    stacks(events).0.last().copied()
+}
+
+pub fn redo_target(events: &[DraftEvent]) -> Option<i32> {
+    stacks(events).1.last().copied()
+}
+
+// Whether applying `event` (or reverting it, when `forward` is false) adds its
+// rows to the board rather than removing them.
+pub fn adds_rows(event: &DraftEvent, forward: bool) -> bool {
+    (event.event_type == DraftEventType::Draft) == forward
+}
+
+// The board as it stood right after `up_to` (or after the whole log), in the
+// order the players were drafted.
+pub fn replay(events: &[DraftEvent], up_to: Option<i32>) -> Vec<DraftedPlayer> {
+    let by_id: HashMap<i32, &DraftEvent> = events.iter().map(|event| (event.id, event)).collect();
+    let mut board: HashMap<i32, DraftedPlayer> = HashMap::new();
+
+    for event in events
+        .iter()
+        .take_while(|event| up_to.is_none_or(|up_to| event.id <= up_to))
+    {
+        let (applied, forward) = match event.event_type {
+            DraftEventType::Undo => (event.target_event_id.and_then(|id| by_id.get(&id)), false),
+            DraftEventType::Redo => (event.target_event_id.and_then(|id| by_id.get(&id)), true),
+            _ => (Some(&event), true),
+        };
+        let Some(applied) = applied else {
+            continue;
+        };
+
+        for drafted_player in &applied.drafted_players {
+            if adds_rows(applied, forward) {
+                board.insert(drafted_player.player_id, drafted_player.clone());
+            } else {
+                board.remove(&drafted_player.player_id);
+            }
+        }
+    }
+
+    let mut board: Vec<DraftedPlayer> = board.into_values().collect();
+    board.sort_by_key(|drafted_player| drafted_player.drafted_at);
+    board
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use tokio::sync::broadcast;
+
+use crate::models::draft_rooms::DraftRoomMessage;
+
+// Messages a slow client can fall behind by before it starts missing them.
+const ROOM_CAPACITY: usize = 64;
+
+struct DraftRoom {
+    sender: broadcast::Sender<DraftRoomMessage>,
+    // Connection id -> display name.
+    connected: HashMap<u64, String>,
+}
+
+#[derive(Default)]
+struct DraftRooms {
+    rooms: HashMap<i32, DraftRoom>,
+    next_connection_id: u64,
+}
+
+// Rooms only live in memory, so they're per server process and empty after a
+// restart. A room goes away once nobody is connected or subscribed.
+static DRAFT_ROOMS: LazyLock<Mutex<DraftRooms>> = LazyLock::new(Default::default);
+
+fn connected_names(room: &DraftRoom) -> Vec<String> {
+    let mut names: Vec<String> = room.connected.values().cloned().collect();
+    names.sort();
+    names
+}
+
+fn room(draft_rooms: &mut DraftRooms, room_id: i32) -> &mut DraftRoom {
+    draft_rooms
+        .rooms
+        .entry(room_id)
+        .or_insert_with(|| DraftRoom {
+            sender: broadcast::channel(ROOM_CAPACITY).0,
+            connected: HashMap::new(),
+        })
+}
+
+fn is_unused(room: &DraftRoom) -> bool {
+    room.connected.is_empty() && room.sender.receiver_count() == 0
+}
+
+// Listens to a board's room without showing up in its presence list.
+pub fn subscribe(room_id: i32) -> broadcast::Receiver<DraftRoomMessage> {
+    let mut draft_rooms = DRAFT_ROOMS.lock().unwrap();
+    room(&mut draft_rooms, room_id).sender.subscribe()
+}
+
+// Adds a client to a board's room. Returns its connection id and a receiver
+// for everything broadcast from now on, starting with its own presence
+// update.
+pub fn join(room_id: i32, name: String) -> (u64, broadcast::Receiver<DraftRoomMessage>) {
+    let mut draft_rooms = DRAFT_ROOMS.lock().unwrap();
+    draft_rooms.next_connection_id += 1;
+    let connection_id = draft_rooms.next_connection_id;
+
+    let room = room(&mut draft_rooms, room_id);
+    room.connected.insert(connection_id, name);
+    let receiver = room.sender.subscribe();
+    let _ = room.sender.send(DraftRoomMessage::Presence {
+        connected: connected_names(room),
+    });
+
+    (connection_id, receiver)
+}
+
+// Callers drop their receiver first so an emptied room can be removed.
+pub fn leave(room_id: i32, connection_id: u64) {
+    let mut draft_rooms = DRAFT_ROOMS.lock().unwrap();
+    let Some(room) = draft_rooms.rooms.get_mut(&room_id) else {
+        return;
+    };
+    room.connected.remove(&connection_id);
+    let _ = room.sender.send(DraftRoomMessage::Presence {
+        connected: connected_names(room),
+    });
+    if is_unused(room) {
+        draft_rooms.rooms.remove(&room_id);
+    }
+}
+
+// Sends a board change to everyone in the room. Does nothing when nobody is
+// listening.
+pub fn broadcast(room_id: i32, message: DraftRoomMessage) {
+    let mut draft_rooms = DRAFT_ROOMS.lock().unwrap();
+    let Some(room) = draft_rooms.rooms.get(&room_id) else {
+        return;
+    };
+    let _ = room.sender.send(message);
+    // Event streams don't say when they close, so unused rooms are also
+    // cleared out here.
+    if is_unused(room) {
+        draft_rooms.rooms.remove(&room_id);
+    }
+}
+
+pub fn broadcast_all(message: DraftRoomMessage) {
+    let mut draft_rooms = DRAFT_ROOMS.lock().unwrap();
+    for room in draft_rooms.rooms.values() {
+        let _ = room.sender.send(message.clone());
+    }
+    draft_rooms.rooms.retain(|_, room| !is_unused(room));
+}
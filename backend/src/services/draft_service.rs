@@ -0,0 +1,600 @@
+use std::fmt;
+
+use crate::models::leagues::{
+    DraftOrder, DraftPick, DraftSlot, League, LeagueTeam, TeamBudget, TradePicksRequest,
+};
+
+#[derive(Debug)]
+pub enum DraftError {
+    OutOfTurn { on_the_clock: i32 },
+    DraftComplete,
+    MissingWinningBid,
+    UnknownTeam,
+    RosterFull,
+    InvalidBid { max_bid: i32 },
+    MissingKeeperTeam,
+    InvalidKeeperRound,
+    PickTaken,
+    PlayerUnavailable,
+    StalePick,
+    NothingToUndo,
+    NothingToRedo,
+    NoPickInRound,
+    NoPickSlots,
+    EmptyTrade,
+    PickNotOwned { overall_pick: i32 },
+    LeagueHasPicks,
+    Database(sqlx::Error),
+}
+
+impl fmt::Display for DraftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DraftError::OutOfTurn { on_the_clock } => {
+                write!(f, "Team {} is on the clock", on_the_clock)
+            }
+            DraftError::DraftComplete => write!(f, "Every round has already been drafted"),
+            DraftError::MissingWinningBid => {
+                write!(f, "Auction picks need the winning team and price")
+            }
+            DraftError::UnknownTeam => write!(f, "That team isn't in this league"),
+            DraftError::RosterFull => write!(f, "That team's roster is already full"),
+            DraftError::InvalidBid { max_bid } => {
+                write!(f, "Bids must be between $1 and ${}", max_bid)
+            }
+            DraftError::MissingKeeperTeam => {
+                write!(f, "League keepers need the team keeping them")
+            }
+            DraftError::InvalidKeeperRound => write!(f, "Keeper round is outside the draft"),
+            DraftError::PickTaken => write!(f, "That team's pick in this round is already used"),
+            DraftError::PlayerUnavailable => write!(f, "That player has already been drafted"),
+            DraftError::StalePick => write!(f, "That pick has already been made"),
+            DraftError::NothingToUndo => write!(f, "There's nothing to undo"),
+            DraftError::NothingToRedo => write!(f, "There's nothing to redo"),
+            DraftError::NoPickInRound => {
+                write!(f, "That team doesn't own a pick in the keeper round")
+            }
+            DraftError::NoPickSlots => write!(f, "Auction leagues don't have picks to trade"),
+            DraftError::EmptyTrade => write!(f, "A trade needs two teams and at least one pick"),
+            DraftError::PickNotOwned { overall_pick } => {
+                write!(f, "Pick {} isn't owned by that team", overall_pick)
+            }
+            DraftError::LeagueHasPicks => {
+                write!(
+                    f,
+                    "Clear the league's picks before replacing or deleting it"
+                )
+            }
+            DraftError::Database(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DraftError {}
+
+impl From<sqlx::Error> for DraftError {
+    fn from(e: sqlx::Error) -> Self {
+        DraftError::Database(e)
+    }
+}
+
+// Rounds are 1-based; a reversed round runs from the last draft position back
+// to the first.
+fn is_reversed(draft_order: &DraftOrder, round: i32) -> bool {
+    match draft_order {
+        DraftOrder::Linear => false,
+        DraftOrder::Snake => round % 2 == 0,
+        DraftOrder::ThirdRoundReversal => round == 2 || (round >= 3 && round % 2 == 1),
+    }
+}
+
+// Returns (round, draft position) for an overall pick number.
+pub fn pick_position(draft_order: &DraftOrder, team_count: i32, overall_pick: i32) -> (i32, i32) {
+    let round = (overall_pick - 1) / team_count + 1;
+    let index = (overall_pick - 1) % team_count;
+    let draft_position = if is_reversed(draft_order, round) {
+        team_count - index
+    } else {
+        index + 1
+    };
+    (round, draft_position)
+}
+
+// Every pick slot in the draft, owned by the team whose draft position makes
+// it. `teams` must be ordered by draft position.
+pub fn draft_picks(league: &League, teams: &[LeagueTeam]) -> Vec<DraftPick> {
+    let team_count = teams.len() as i32;
+    (1..=league.rounds * team_count)
+        .map(|overall_pick| {
+            let (round, draft_position) =
+                pick_position(&league.draft_order, team_count, overall_pick);
+            let team_id = teams[(draft_position - 1) as usize].id;
+            DraftPick {
+                league_id: league.id,
+                overall_pick,
+                round,
+                original_team_id: team_id,
+                team_id,
+            }
+        })
+        .collect()
+}
+
+// The team that currently owns a pick. Falls back to draft order for leagues
+// without pick slots.
+fn pick_owner(
+    draft_order: &DraftOrder,
+    teams: &[LeagueTeam],
+    picks: &[DraftPick],
+    overall_pick: i32,
+) -> i32 {
+    match picks.iter().find(|pick| pick.overall_pick == overall_pick) {
+        Some(pick) => pick.team_id,
+        None => {
+            let (_, draft_position) = pick_position(draft_order, teams.len() as i32, overall_pick);
+            teams[(draft_position - 1) as usize].id
+        }
+    }
+}
+
+// `teams` must be ordered by draft position. Picks already filled, such as
+// keepers, are skipped, and traded picks are made by their new owner.
+pub fn on_the_clock(
+    league: &League,
+    teams: &[LeagueTeam],
+    picks: &[DraftPick],
+    filled_picks: &[i32],
+) -> Option<DraftSlot> {
+    let team_count = teams.len() as i32;
+    if team_count == 0 {
+        return None;
+    }
+
+    let overall_pick = (league.current_pick..=league.rounds * team_count)
+        .find(|overall_pick| !filled_picks.contains(overall_pick))?;
+    let (round, _) = pick_position(&league.draft_order, team_count, overall_pick);
+    Some(DraftSlot {
+        overall_pick,
+        round,
+        team_id: pick_owner(&league.draft_order, teams, picks, overall_pick),
+    })
+}
+
+// The overall picks a team owns in a given round. Usually one, but trades can
+// leave a team with none or several.
+pub fn team_picks_in_round(
+    draft_order: &DraftOrder,
+    teams: &[LeagueTeam],
+    picks: &[DraftPick],
+    team_id: i32,
+    round: i32,
+) -> Vec<i32> {
+    let team_count = teams.len() as i32;
+    ((round - 1) * team_count + 1..=round * team_count)
+        .filter(|overall_pick| pick_owner(draft_order, teams, picks, *overall_pick) == team_id)
+        .collect()
+}
+
+// Checks that each side of a trade owns the picks it's sending and that none
+// of them have been used yet.
+pub fn validate_trade(
+    teams: &[LeagueTeam],
+    picks: &[DraftPick],
+    filled_picks: &[i32],
+    request: &TradePicksRequest,
+) -> Result<(), DraftError> {
+    if request.team_id == request.other_team_id
+        || (request.sends.is_empty() && request.receives.is_empty())
+    {
+        return Err(DraftError::EmptyTrade);
+    }
+    for team_id in [request.team_id, request.other_team_id] {
+        if !teams.iter().any(|team| team.id == team_id) {
+            return Err(DraftError::UnknownTeam);
+        }
+    }
+
+    let sides = [
+        (request.team_id, &request.sends),
+        (request.other_team_id, &request.receives),
+    ];
+    for (team_id, overall_picks) in sides {
+        for &overall_pick in overall_picks.iter() {
+            if !picks
+                .iter()
+                .any(|pick| pick.overall_pick == overall_pick && pick.team_id == team_id)
+            {
+                return Err(DraftError::PickNotOwned { overall_pick });
+            }
+            if filled_picks.contains(&overall_pick) {
+                return Err(DraftError::PickTaken);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Picks other teams make before the team on the clock picks again, or None
+// when this is its last pick.
+pub fn picks_until_next(slot: &DraftSlot, picks: &[DraftPick]) -> Option<i32> {
+    picks
+        .iter()
+        .filter(|pick| pick.overall_pick > slot.overall_pick && pick.team_id == slot.team_id)
+        .map(|pick| pick.overall_pick - slot.overall_pick - 1)
+        .min()
+}
+
+// Rounds of value a keeper brings: how much later the keeper costs than the
+// round the player is going in by overall ranking. Negative when keeping the
+// player costs more than drafting them.
+pub fn keeper_value(keeper_round: i32, overall_ranking: i32, team_count: i32) -> i32 {
+    let ranking_round = (overall_ranking - 1) / team_count + 1;
+    keeper_round - ranking_round
+}
+
+// `picks` are the (team id, price) of every pick made in the league.
+pub fn team_budgets(
+    league: &League,
+    budget: i32,
+    teams: &[LeagueTeam],
+    picks: &[(i32, Option<i32>)],
+) -> Vec<TeamBudget> {
+    teams
+        .iter()
+        .map(|team| {
+            let team_picks = picks.iter().filter(|(team_id, _)| *team_id == team.id);
+            let spent: i32 = team_picks.clone().filter_map(|(_, price)| *price).sum();
+            let remaining_budget = budget - spent;
+            let open_slots = (league.rounds - team_picks.count() as i32).max(0);
+            TeamBudget {
+                team_id: team.id,
+                remaining_budget,
+                open_slots,
+                max_bid: if open_slots > 0 {
+                    remaining_budget - (open_slots - 1)
+                } else {
+                    0
+                },
+            }
+        })
+        .collect()
+}
+
+// Checks that the team has a roster spot left for another player.
+pub fn validate_open_slot(budgets: &[TeamBudget], team_id: i32) -> Result<&TeamBudget, DraftError> {
+    let budget = budgets
+        .iter()
+        .find(|budget| budget.team_id == team_id)
+        .ok_or(DraftError::UnknownTeam)?;
+    if budget.open_slots == 0 {
+        return Err(DraftError::RosterFull);
+    }
+    Ok(budget)
+}
+
+// Checks a winning bid against the team's budget.
+pub fn validate_bid(budgets: &[TeamBudget], team_id: i32, price: i32) -> Result<(), DraftError> {
+    let budget = validate_open_slot(budgets, team_id)?;
+    if price < 1 || price > budget.max_bid {
+        return Err(DraftError::InvalidBid {
+            max_bid: budget.max_bid,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    fn league(draft_order: DraftOrder, rounds: i32, current_pick: i32) -> League {
+        League {
+            id: 1,
+            user_id: 1,
+            name: "Test".to_string(),
+            draft_order,
+            rounds,
+            current_pick,
+            auction_budget: None,
+            pick_seconds: None,
+            pick_deadline: None,
+            created_at: OffsetDateTime::UNIX_EPOCH,
+        }
+    }
+
+    // Team ids are 10x the draft position so they can't be mistaken for one.
+    fn teams(count: i32) -> Vec<LeagueTeam> {
+        (1..=count)
+            .map(|draft_position| LeagueTeam {
+                id: draft_position * 10,
+                league_id: 1,
+                name: format!("Team {}", draft_position),
+                draft_position,
+            })
+            .collect()
+    }
+
+    fn draft_positions(draft_order: DraftOrder, team_count: i32, rounds: i32) -> Vec<i32> {
+        (1..=team_count * rounds)
+            .map(|overall_pick| pick_position(&draft_order, team_count, overall_pick).1)
+            .collect()
+    }
+
+    #[test]
+    fn linear_order_repeats_every_round() {
+        assert_eq!(
+            draft_positions(DraftOrder::Linear, 3, 3),
+            vec![1, 2, 3, 1, 2, 3, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn snake_order_reverses_every_other_round() {
+        assert_eq!(
+            draft_positions(DraftOrder::Snake, 3, 4),
+            vec![1, 2, 3, 3, 2, 1, 1, 2, 3, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn third_round_reversal_runs_round_three_like_round_two() {
+        assert_eq!(
+            draft_positions(DraftOrder::ThirdRoundReversal, 3, 5),
+            vec![1, 2, 3, 3, 2, 1, 3, 2, 1, 1, 2, 3, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn rounds_turn_over_at_the_last_team() {
+        assert_eq!(pick_position(&DraftOrder::Snake, 12, 12), (1, 12));
+        assert_eq!(pick_position(&DraftOrder::Snake, 12, 13), (2, 12));
+        assert_eq!(pick_position(&DraftOrder::Snake, 12, 24), (2, 1));
+        assert_eq!(pick_position(&DraftOrder::Snake, 12, 25), (3, 1));
+    }
+
+    #[test]
+    fn a_single_team_makes_every_pick() {
+        assert_eq!(draft_positions(DraftOrder::Snake, 1, 3), vec![1, 1, 1]);
+        assert_eq!(
+            draft_positions(DraftOrder::ThirdRoundReversal, 1, 3),
+            vec![1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn on_the_clock_follows_draft_order() {
+        let teams = teams(3);
+        let slot = on_the_clock(&league(DraftOrder::Snake, 2, 4), &teams, &[], &[]).unwrap();
+
+        assert_eq!(slot.overall_pick, 4);
+        assert_eq!(slot.round, 2);
+        assert_eq!(slot.team_id, 30);
+    }
+
+    #[test]
+    fn on_the_clock_is_none_after_the_last_pick() {
+        let teams = teams(2);
+
+        assert!(on_the_clock(&league(DraftOrder::Snake, 2, 4), &teams, &[], &[]).is_some());
+        assert!(on_the_clock(&league(DraftOrder::Snake, 2, 5), &teams, &[], &[]).is_none());
+        assert!(on_the_clock(&league(DraftOrder::Snake, 2, 1), &[], &[], &[]).is_none());
+    }
+
+    #[test]
+    fn on_the_clock_skips_kept_picks() {
+        let teams = teams(2);
+        let league = league(DraftOrder::Snake, 2, 2);
+
+        assert_eq!(
+            on_the_clock(&league, &teams, &[], &[2, 3])
+                .unwrap()
+                .overall_pick,
+            4
+        );
+        assert!(on_the_clock(&league, &teams, &[], &[2, 3, 4]).is_none());
+    }
+
+    #[test]
+    fn team_picks_in_round_follow_the_snake() {
+        let teams = teams(3);
+
+        assert_eq!(
+            team_picks_in_round(&DraftOrder::Snake, &teams, &[], 10, 1),
+            vec![1]
+        );
+        assert_eq!(
+            team_picks_in_round(&DraftOrder::Snake, &teams, &[], 10, 2),
+            vec![6]
+        );
+        assert_eq!(
+            team_picks_in_round(&DraftOrder::Linear, &teams, &[], 10, 2),
+            vec![4]
+        );
+        assert!(team_picks_in_round(&DraftOrder::Snake, &teams, &[], 99, 1).is_empty());
+    }
+
+    // Pick slots for a 3-team, 2-round snake with the first pick traded from
+    // the first team to the third.
+    fn traded_picks(league: &League, teams: &[LeagueTeam]) -> Vec<DraftPick> {
+        let mut picks = draft_picks(league, teams);
+        picks[0].team_id = 30;
+        picks
+    }
+
+    #[test]
+    fn traded_picks_are_made_by_their_new_owner() {
+        let teams = teams(3);
+        let league = league(DraftOrder::Snake, 2, 1);
+        let picks = traded_picks(&league, &teams);
+
+        assert_eq!(
+            on_the_clock(&league, &teams, &picks, &[]).unwrap().team_id,
+            30
+        );
+        assert_eq!(picks[0].original_team_id, 10);
+        assert_eq!(
+            team_picks_in_round(&league.draft_order, &teams, &picks, 30, 1),
+            vec![1, 3]
+        );
+        assert!(team_picks_in_round(&league.draft_order, &teams, &picks, 10, 1).is_empty());
+    }
+
+    fn trade(sends: Vec<i32>, receives: Vec<i32>) -> TradePicksRequest {
+        TradePicksRequest {
+            team_id: 30,
+            other_team_id: 20,
+            sends,
+            receives,
+        }
+    }
+
+    #[test]
+    fn validate_trade_checks_ownership_of_both_sides() {
+        let teams = teams(3);
+        let league = league(DraftOrder::Snake, 2, 1);
+        let picks = traded_picks(&league, &teams);
+
+        // The third team can send the pick it got in the earlier trade.
+        assert!(validate_trade(&teams, &picks, &[], &trade(vec![1], vec![2])).is_ok());
+        assert!(matches!(
+            validate_trade(&teams, &picks, &[], &trade(vec![1], vec![3])),
+            Err(DraftError::PickNotOwned { overall_pick: 3 })
+        ));
+        assert!(matches!(
+            validate_trade(&teams, &picks, &[1], &trade(vec![1], vec![])),
+            Err(DraftError::PickTaken)
+        ));
+    }
+
+    #[test]
+    fn validate_trade_rejects_empty_and_one_sided_teams() {
+        let teams = teams(3);
+        let league = league(DraftOrder::Snake, 2, 1);
+        let picks = draft_picks(&league, &teams);
+
+        assert!(matches!(
+            validate_trade(&teams, &picks, &[], &trade(vec![], vec![])),
+            Err(DraftError::EmptyTrade)
+        ));
+        let mut with_itself = trade(vec![3], vec![]);
+        with_itself.other_team_id = 30;
+        assert!(matches!(
+            validate_trade(&teams, &picks, &[], &with_itself),
+            Err(DraftError::EmptyTrade)
+        ));
+        let mut unknown = trade(vec![3], vec![]);
+        unknown.other_team_id = 99;
+        assert!(matches!(
+            validate_trade(&teams, &picks, &[], &unknown),
+            Err(DraftError::UnknownTeam)
+        ));
+    }
+
+    #[test]
+    fn keeper_value_compares_against_the_ranking_round() {
+        // Ranked 13th in a 12-team league goes in round 2.
+        assert_eq!(keeper_value(5, 13, 12), 3);
+        assert_eq!(keeper_value(2, 13, 12), 0);
+        assert_eq!(keeper_value(1, 13, 12), -1);
+        // The last pick of a round still belongs to that round.
+        assert_eq!(keeper_value(1, 12, 12), 0);
+    }
+
+    #[test]
+    fn team_budgets_hold_back_a_dollar_per_other_open_slot() {
+        let league = league(DraftOrder::Snake, 3, 1);
+        let teams = teams(2);
+        let budgets = team_budgets(&league, 200, &teams, &[(10, Some(50))]);
+
+        assert_eq!(budgets[0].remaining_budget, 150);
+        assert_eq!(budgets[0].open_slots, 2);
+        assert_eq!(budgets[0].max_bid, 149);
+        assert_eq!(budgets[1].remaining_budget, 200);
+        assert_eq!(budgets[1].open_slots, 3);
+        assert_eq!(budgets[1].max_bid, 198);
+    }
+
+    #[test]
+    fn team_budgets_let_the_last_slot_take_everything_left() {
+        let league = league(DraftOrder::Snake, 2, 1);
+        let teams = teams(1);
+        let budgets = team_budgets(&league, 200, &teams, &[(10, Some(120))]);
+
+        assert_eq!(budgets[0].open_slots, 1);
+        assert_eq!(budgets[0].max_bid, 80);
+    }
+
+    #[test]
+    fn full_rosters_cannot_bid() {
+        let league = league(DraftOrder::Snake, 2, 1);
+        let teams = teams(1);
+        let budgets = team_budgets(&league, 200, &teams, &[(10, Some(150)), (10, Some(50))]);
+
+        assert_eq!(budgets[0].open_slots, 0);
+        assert_eq!(budgets[0].max_bid, 0);
+        assert!(matches!(
+            validate_bid(&budgets, 10, 1),
+            Err(DraftError::RosterFull)
+        ));
+    }
+
+    #[test]
+    fn min_bids_on_every_slot_leave_exactly_a_dollar() {
+        let league = league(DraftOrder::Snake, 3, 1);
+        let teams = teams(1);
+        let budgets = team_budgets(&league, 3, &teams, &[(10, Some(1)), (10, Some(1))]);
+
+        assert_eq!(budgets[0].max_bid, 1);
+        assert!(validate_bid(&budgets, 10, 1).is_ok());
+        assert!(matches!(
+            validate_bid(&budgets, 10, 2),
+            Err(DraftError::InvalidBid { max_bid: 1 })
+        ));
+    }
+
+    #[test]
+    fn a_spent_budget_still_has_open_slots() {
+        // Keepers take a roster spot without a bid, so a team that's spent
+        // down to nothing can still keep a player until its roster is full.
+        let league = league(DraftOrder::Snake, 3, 1);
+        let teams = teams(1);
+        let budgets = team_budgets(&league, 2, &teams, &[(10, Some(2))]);
+
+        assert_eq!(budgets[0].max_bid, -1);
+        assert!(validate_open_slot(&budgets, 10).is_ok());
+        assert!(matches!(
+            validate_open_slot(&budgets, 99),
+            Err(DraftError::UnknownTeam)
+        ));
+
+        let budgets = team_budgets(&league, 2, &teams, &[(10, Some(2)), (10, None), (10, None)]);
+        assert!(matches!(
+            validate_open_slot(&budgets, 10),
+            Err(DraftError::RosterFull)
+        ));
+    }
+
+    #[test]
+    fn validate_bid_allows_one_dollar_up_to_the_max_bid() {
+        let league = league(DraftOrder::Snake, 3, 1);
+        let teams = teams(1);
+        let budgets = team_budgets(&league, 200, &teams, &[]);
+
+        assert!(validate_bid(&budgets, 10, 1).is_ok());
+        assert!(validate_bid(&budgets, 10, 198).is_ok());
+        assert!(matches!(
+            validate_bid(&budgets, 10, 199),
+            Err(DraftError::InvalidBid { max_bid: 198 })
+        ));
+        assert!(matches!(
+            validate_bid(&budgets, 10, 0),
+            Err(DraftError::InvalidBid { .. })
+        ));
+        assert!(matches!(
+            validate_bid(&budgets, 99, 1),
+            Err(DraftError::UnknownTeam)
+        ));
+    }
+}
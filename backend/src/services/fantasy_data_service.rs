@@ -1,16 +1,50 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use headless_chrome::Browser;
+use time::OffsetDateTime;
 
 use crate::database::connection::get_db_connection;
 use crate::database::operations::fantasy_data_operations::{
-    bulk_save_players, bulk_save_rankings, bulk_save_stats, delete_old_data,
-    record_fantasy_data_update,
+    bulk_save_game_logs, bulk_save_players, bulk_save_projections, bulk_save_rankings,
+    bulk_save_stats, delete_old_data, record_fantasy_data_update,
 };
+use crate::models::draft_rooms::DraftRoomMessage;
+use crate::models::game_logs::GameLog;
+use crate::models::stats::Stats;
 use crate::scrapers::{
-    players_scraper::PlayersScraper, rankings_scraper::RankingsScraper, stats_scraper::StatsScraper,
+    game_logs_scraper::GameLogsScraper, players_scraper::PlayersScraper,
+    projections_scraper::ProjectionsScraper, rankings_scraper::RankingsScraper,
+    stats_scraper::StatsScraper,
 };
+use crate::services::draft_room_service;
+
+// Stats always come from the most recent regular season that has finished.
+pub fn latest_stats_season() -> i32 {
+    OffsetDateTime::now_utc().year() - 1
+}
+
+// The season stats tables don't have points/yards allowed columns, so DST
+// season lines get them summed from their weekly game logs.
+fn add_allowed_totals(stats: &mut [Stats], game_logs: &[GameLog]) {
+    let mut totals: HashMap<(i32, i32), (f64, f64)> = HashMap::new();
+    for game_log in game_logs {
+        let total = totals
+            .entry((game_log.player_id, game_log.season))
+            .or_default();
+        total.0 += game_log.base.points_allowed;
+        total.1 += game_log.base.yards_allowed;
+    }
+
+    for stat in stats {
+        if let Some((points_allowed, yards_allowed)) = totals.get(&(stat.player_id, stat.season)) {
+            stat.base.points_allowed = *points_allowed;
+            stat.base.yards_allowed = *yards_allowed;
+        }
+    }
+}
 
-pub async fn update() -> Result<()> {
+pub async fn update(backfill_seasons: u32) -> Result<()> {
     let browser = Browser::default()?;
     let tab = browser.new_tab()?;
 
@@ -22,9 +56,37 @@ pub async fn update() -> Result<()> {
         player_tasks.len()
     );
 
+    let latest_season = latest_stats_season();
+    let stats_seasons: Vec<i32> =
+        (latest_season - i32::try_from(backfill_seasons)?..=latest_season).collect();
     let stats_scraper = StatsScraper::new(&tab);
-    let stats = stats_scraper.scrape().await?;
-    eprintln!("Scraped {} player stat lines", stats.len());
+    let mut stats = Vec::new();
+    for season in &stats_seasons {
+        let season_stats = stats_scraper.scrape(*season).await?;
+        eprintln!(
+            "Scraped {} player stat lines for {}",
+            season_stats.len(),
+            season
+        );
+        stats.extend(season_stats);
+    }
+
+    let projections_scraper = ProjectionsScraper::new(&tab);
+    let projections = projections_scraper.scrape().await?;
+    eprintln!("Scraped {} player projections", projections.len());
+
+    let mut game_logs = Vec::new();
+    for season in &stats_seasons {
+        let season_game_logs =
+            GameLogsScraper::process_tasks(player_tasks.clone(), *season).await?;
+        eprintln!(
+            "Scraped {} weekly game logs for {}",
+            season_game_logs.len(),
+            season
+        );
+        game_logs.extend(season_game_logs);
+    }
+    add_allowed_totals(&mut stats, &game_logs);
 
     let players = PlayersScraper::process_tasks(player_tasks).await?;
     eprintln!("Scraped {} player profiles", players.len());
@@ -41,12 +103,15 @@ pub async fn update() -> Result<()> {
 
     let conn = get_db_connection().await?;
     let mut tx = conn.begin().await?;
-    delete_old_data(&mut tx).await?;
+    delete_old_data(&stats_seasons, &mut tx).await?;
     bulk_save_players(&players, &mut tx).await?;
     bulk_save_rankings(&rankings, &mut tx).await?;
     bulk_save_stats(&stats, &mut tx).await?;
+    bulk_save_projections(&projections, &mut tx).await?;
+    bulk_save_game_logs(&game_logs, &mut tx).await?;
     record_fantasy_data_update(&mut tx).await?;
     tx.commit().await?;
+    draft_room_service::broadcast_all(DraftRoomMessage::FantasyDataRefreshed);
 
     Ok(())
 }
@@ -0,0 +1,209 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::models::mock_drafts::{MockCandidate, MockDraft};
+use crate::models::players::Position;
+use crate::models::roster::RosterSettings;
+use crate::services::draft_service;
+
+// (round, draft position) of a mock draft pick.
+pub fn pick_position(mock_draft: &MockDraft, overall_pick: i32) -> (i32, i32) {
+    draft_service::pick_position(&mock_draft.draft_order, mock_draft.team_count, overall_pick)
+}
+
+pub fn on_the_clock(mock_draft: &MockDraft) -> Option<i32> {
+    if mock_draft.current_pick > mock_draft.rounds * mock_draft.team_count {
+        return None;
+    }
+    Some(pick_position(mock_draft, mock_draft.current_pick).1)
+}
+
+// Each pick gets its own generator derived from the mock's seed, so a replay
+// makes the same picks no matter how the user's turns are spaced out.
+pub fn pick_rng(mock_draft: &MockDraft, overall_pick: i32) -> StdRng {
+    StdRng::seed_from_u64(mock_draft.seed.wrapping_add(overall_pick as i64) as u64)
+}
+
+// Most of a position a bot will roster. Kickers and defenses stop at their
+// starting slots, and one backup quarterback/tight end is allowed.
+fn position_limit(roster_settings: &RosterSettings, position: &Position) -> i32 {
+    let slots = roster_settings.slots(position)
+        + match position {
+            Position::QB => roster_settings.superflex,
+            _ => 0,
+        };
+    match position {
+        Position::RB | Position::WR => i32::MAX,
+        Position::K | Position::DST => slots,
+        _ if slots == 0 => 0,
+        _ => slots + 1,
+    }
+}
+
+// Picks the available player with the best noisy ADP that fits the bot's
+// roster. Kickers and defenses wait for the last rounds, and once the rounds
+// left only cover the bot's empty starting slots it drafts for need.
+pub fn bot_pick(
+    candidates: &[MockCandidate],
+    bot_roster: &[Position],
+    round: i32,
+    rounds: i32,
+    roster_settings: &RosterSettings,
+    rng: &mut StdRng,
+) -> Option<i32> {
+    let count = |position: &Position| bot_roster.iter().filter(|p| *p == position).count() as i32;
+    let open_starters = |position: &Position| {
+        (roster_settings.slots(position) + roster_settings.flex_slots(position) - count(position))
+            .max(0)
+    };
+    let unfilled: i32 = [
+        Position::QB,
+        Position::RB,
+        Position::WR,
+        Position::TE,
+        Position::K,
+        Position::DST,
+        Position::DL,
+        Position::LB,
+        Position::DB,
+    ]
+    .iter()
+    .map(|position| roster_settings.slots(position) - count(position))
+    .filter(|open| *open > 0)
+    .sum();
+    let rounds_left = rounds - round + 1;
+    let late_rounds = roster_settings.k + roster_settings.dst;
+
+    candidates
+        .iter()
+        .filter(|candidate| {
+            count(&candidate.position) < position_limit(roster_settings, &candidate.position)
+        })
+        .filter(|candidate| {
+            !matches!(candidate.position, Position::K | Position::DST) || rounds_left <= late_rounds
+        })
+        .filter(|candidate| rounds_left > unfilled || open_starters(&candidate.position) > 0)
+        .map(|candidate| {
+            let noisy_adp = candidate.average as f64
+                + standard_normal(rng) * candidate.standard_deviation as f64;
+            (candidate.player_id, noisy_adp)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(player_id, _)| player_id)
+        .or_else(|| candidates.first().map(|candidate| candidate.player_id))
+}
+
+// Box-Muller transform.
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(player_id: i32, position: Position, average: f32) -> MockCandidate {
+        MockCandidate {
+            player_id,
+            position,
+            average,
+            standard_deviation: 0.0,
+        }
+    }
+
+    fn pick(candidates: &[MockCandidate], bot_roster: &[Position], round: i32) -> Option<i32> {
+        bot_pick(
+            candidates,
+            bot_roster,
+            round,
+            15,
+            &RosterSettings::default(),
+            &mut StdRng::seed_from_u64(7),
+        )
+    }
+
+    #[test]
+    fn takes_the_best_adp_without_noise() {
+        let candidates = [
+            candidate(1, Position::WR, 3.0),
+            candidate(2, Position::RB, 1.0),
+            candidate(3, Position::QB, 2.0),
+        ];
+        assert_eq!(pick(&candidates, &[], 1), Some(2));
+    }
+
+    #[test]
+    fn kickers_and_defenses_wait_for_the_last_rounds() {
+        let candidates = [
+            candidate(1, Position::K, 1.0),
+            candidate(2, Position::DST, 2.0),
+            candidate(3, Position::WR, 3.0),
+        ];
+        assert_eq!(pick(&candidates, &[], 1), Some(3));
+        assert_eq!(pick(&candidates, &[], 14), Some(1));
+    }
+
+    #[test]
+    fn stops_at_the_position_limit() {
+        let candidates = [
+            candidate(1, Position::QB, 1.0),
+            candidate(2, Position::RB, 2.0),
+        ];
+        assert_eq!(pick(&candidates, &[Position::QB], 2), Some(1));
+        assert_eq!(pick(&candidates, &[Position::QB, Position::QB], 3), Some(2));
+    }
+
+    #[test]
+    fn drafts_for_need_once_the_rounds_left_run_out() {
+        let bot_roster = [
+            Position::QB,
+            Position::RB,
+            Position::RB,
+            Position::WR,
+            Position::WR,
+            Position::K,
+            Position::DST,
+        ];
+        let candidates = [
+            candidate(1, Position::QB, 1.0),
+            candidate(2, Position::TE, 50.0),
+        ];
+        // Two rounds left for one open tight end slot still leaves room for
+        // the best player; one round left for it doesn't.
+        assert_eq!(pick(&candidates, &bot_roster, 14), Some(1));
+        assert_eq!(pick(&candidates, &bot_roster, 15), Some(2));
+    }
+
+    #[test]
+    fn falls_back_to_the_first_candidate() {
+        let candidates = [candidate(1, Position::K, 1.0)];
+        assert_eq!(pick(&candidates, &[Position::K], 15), Some(1));
+        assert_eq!(pick(&[], &[], 1), None);
+    }
+
+    #[test]
+    fn same_seed_makes_the_same_pick() {
+        let candidates: Vec<MockCandidate> = (1..=20)
+            .map(|player_id| MockCandidate {
+                player_id,
+                position: Position::WR,
+                average: player_id as f32,
+                standard_deviation: 5.0,
+            })
+            .collect();
+        let settings = RosterSettings::default();
+        let make_pick = |seed: u64| {
+            bot_pick(
+                &candidates,
+                &[],
+                1,
+                15,
+                &settings,
+                &mut StdRng::seed_from_u64(seed),
+            )
+        };
+        assert_eq!(make_pick(42), make_pick(42));
+    }
+}
@@ -1 +1,14 @@
+pub mod best_ball_service;
+pub mod board_service;
+pub mod draft_event_service;
+pub mod draft_room_service;
+pub mod draft_service;
 pub mod fantasy_data_service;
+pub mod mock_draft_service;
+pub mod pick_clock_service;
+pub mod recommendation_service;
+pub mod roster_service;
+pub mod scoring_service;
+pub mod tier_service;
+pub mod valuation_service;
+pub mod vbd_service;
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use crate::database::operations::{
+    drafted_player_operations, league_operations, player_operations,
+};
+use crate::models::draft_rooms::DraftRoomMessage;
+use crate::models::drafted_players::DraftRequest;
+use crate::services::draft_room_service;
+use crate::services::draft_service::DraftError;
+
+const TICK: Duration = Duration::from_secs(1);
+
+// Checks every board's pick clock once a second and autopicks for any team
+// that has run out of time.
+pub fn spawn() {
+    actix_web::rt::spawn(async {
+        let mut interval = actix_web::rt::time::interval(TICK);
+        loop {
+            interval.tick().await;
+            let user_ids = match league_operations::get_expired_pick_clocks().await {
+                Ok(user_ids) => user_ids,
+                Err(e) => {
+                    eprintln!("Failed to check pick clocks: {}", e);
+                    continue;
+                }
+            };
+            for user_id in user_ids {
+                if let Err(e) = autopick(user_id).await {
+                    eprintln!("Failed to autopick for user {}: {}", user_id, e);
+                }
+            }
+        }
+    });
+}
+
+// Takes the best available player by overall rank for the team on the clock.
+// The pick is tied to the overall pick that expired, so a pick the user makes
+// in the meantime wins.
+async fn autopick(user_id: i32) -> Result<(), DraftError> {
+    let Some(league) = league_operations::get_league(user_id).await? else {
+        return Ok(());
+    };
+    let Some(slot) = league.on_the_clock else {
+        league_operations::set_pick_clock(user_id, None).await?;
+        return Ok(());
+    };
+
+    let Some(player_id) = player_operations::get_best_available(user_id).await? else {
+        league_operations::set_pick_clock(user_id, None).await?;
+        return Ok(());
+    };
+
+    let request = DraftRequest {
+        team_id: Some(slot.team_id),
+        overall_pick: Some(slot.overall_pick),
+        ..Default::default()
+    };
+    match drafted_player_operations::draft_player(user_id, player_id, &request).await {
+        Ok(drafted_player) => {
+            draft_room_service::broadcast(user_id, DraftRoomMessage::Drafted { drafted_player });
+            Ok(())
+        }
+        Err(DraftError::StalePick) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
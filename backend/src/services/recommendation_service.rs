@@ -0,0 +1,287 @@
+use round::round;
+
+use crate::models::players::PlayerResponse;
+use crate::models::recommendations::Recommendation;
+use crate::models::roster::{RosterPlayer, RosterSettings, Slot};
+use crate::services::roster_service;
+
+// Adjustments are in overall-rank spots. An open starting slot is worth about
+// a round, a flex-only fit half that, and a player who'd only sit on the bench
+// drops about a round.
+const STARTER_NEED: f64 = 12.0;
+const FLEX_NEED: f64 = 6.0;
+const BENCH_ONLY: f64 = -12.0;
+const NO_SLOT: f64 = -50.0;
+// Spots a player drops for each of my starters sharing their bye week.
+const BYE_OVERLAP: f64 = 2.0;
+// Rank spots per projected point lost by waiting until the next pick.
+const DROPOFF_WEIGHT: f64 = 0.25;
+
+pub struct RecommendationSettings<'a> {
+    pub roster: &'a RosterSettings,
+    pub my_players: &'a [RosterPlayer],
+    // Picks other teams make before my next one.
+    pub picks_until_next: i32,
+    pub limit: usize,
+}
+
+// Ranks undrafted players by consensus rank, adjusted for the open slot they'd
+// fill on my roster, how many of my starters share their bye week, and how
+// many points their position drops off by my next pick.
+pub fn recommend(
+    players: &[PlayerResponse],
+    settings: &RecommendationSettings,
+) -> Vec<Recommendation> {
+    let (slots, _) = roster_service::assign_slots(settings.roster, settings.my_players);
+    let starter_byes: Vec<i32> = slots
+        .iter()
+        .filter(|roster_slot| roster_slot.slot != Slot::BENCH)
+        .filter_map(|roster_slot| roster_slot.player.as_ref()?.bye_week)
+        .collect();
+
+    let mut available: Vec<&PlayerResponse> = players.iter().filter(|p| !p.drafted).collect();
+    available.sort_by_key(|player| player.rankings.overall);
+
+    let mut recommendations: Vec<Recommendation> = available
+        .iter()
+        .map(|player| {
+            let mut score = player.rankings.overall as f64;
+            let mut reasons = vec![format!("Ranked #{} overall", player.rankings.overall)];
+
+            let open_slot = slots
+                .iter()
+                .filter(|roster_slot| roster_slot.player.is_none())
+                .map(|roster_slot| &roster_slot.slot)
+                .filter(|slot| roster_service::slot_fits(slot, &player.position))
+                .min();
+            let (need, reason) = match open_slot {
+                Some(Slot::BENCH) => (
+                    BENCH_ONLY,
+                    format!("your {} starters are set", player.position),
+                ),
+                Some(slot @ (Slot::FLEX | Slot::SUPERFLEX)) => {
+                    (FLEX_NEED, format!("fits your open {:?} slot", slot))
+                }
+                Some(_) => (
+                    STARTER_NEED,
+                    format!("fills an open {} slot", player.position),
+                ),
+                None => (NO_SLOT, "no open roster slot fits".to_string()),
+            };
+            score -= need;
+            reasons.push(reason);
+
+            if let Some(bye_week) = player.bye_week {
+                let overlaps = starter_byes.iter().filter(|bye| **bye == bye_week).count();
+                if overlaps > 0 {
+                    score += BYE_OVERLAP * overlaps as f64;
+                    reasons.push(format!(
+                        "week {} bye overlaps {} of your starters",
+                        bye_week, overlaps
+                    ));
+                }
+            }
+
+            let dropoff = dropoff(player, &available, settings.picks_until_next);
+            if dropoff >= 1.0 {
+                score -= dropoff * DROPOFF_WEIGHT;
+                reasons.push(format!(
+                    "the best {} left at your next pick projects {:.1} fewer points",
+                    player.position, dropoff
+                ));
+            }
+
+            Recommendation {
+                player: (*player).clone(),
+                score: round(score, 1),
+                explanation: reasons.join("; "),
+            }
+        })
+        .collect();
+
+    recommendations.sort_by(|a, b| a.score.total_cmp(&b.score));
+    recommendations.truncate(settings.limit);
+    recommendations
+}
+
+// Projected points between a player and the best player at their position
+// still expected to be there at my next pick, assuming the other teams take
+// the next best players by overall rank. `available` is in rank order.
+fn dropoff(player: &PlayerResponse, available: &[&PlayerResponse], picks_until_next: i32) -> f64 {
+    let Some(points) = player.projections.points else {
+        return 0.0;
+    };
+    let next_best = available
+        .iter()
+        .filter(|other| other.id != player.id)
+        .skip(picks_until_next.max(0) as usize)
+        .filter(|other| other.position == player.position)
+        .filter_map(|other| other.projections.points)
+        .max_by(|a, b| a.total_cmp(b))
+        .unwrap_or(0.0);
+    (points - next_best).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::players::{Position, Team};
+    use crate::models::rankings::RankingsBase;
+    use crate::models::stats::{StatsBase, StatsResponse};
+
+    fn projection(points: Option<f64>) -> StatsResponse {
+        StatsResponse {
+            season: None,
+            base: StatsBase::default(),
+            points,
+            points_per_game: None,
+            spike_weeks: None,
+            best_ball_points: None,
+        }
+    }
+
+    // An undrafted player ranked `overall` with no bye week.
+    fn ranked(id: i32, position: Position, overall: i32, projected: Option<f64>) -> PlayerResponse {
+        PlayerResponse {
+            id,
+            name: format!("Player {}", id),
+            position,
+            team: Team::KC,
+            bye_week: None,
+            height: String::new(),
+            weight: String::new(),
+            age: None,
+            college: String::new(),
+            rankings: RankingsBase {
+                overall,
+                position: overall,
+                best: overall,
+                worst: overall,
+                average: overall as f32,
+                standard_deviation: 0.0,
+                tier: None,
+                overall_tier: None,
+            },
+            stats: projection(None),
+            projections: projection(projected),
+            drafted: false,
+            drafted_by_me: false,
+            keeper_round: None,
+            keeper_value: None,
+            auction_value: None,
+            vorp: None,
+            vbd_rank: None,
+        }
+    }
+
+    fn my_player(id: i32, position: Position, bye_week: Option<i32>) -> RosterPlayer {
+        RosterPlayer {
+            id,
+            name: format!("Player {}", id),
+            position,
+            team: Team::KC,
+            bye_week,
+            projected_points: Some(200.0),
+        }
+    }
+
+    fn recommendations(
+        players: &[PlayerResponse],
+        my_players: &[RosterPlayer],
+        picks_until_next: i32,
+    ) -> Vec<Recommendation> {
+        recommend(
+            players,
+            &RecommendationSettings {
+                roster: &RosterSettings::default(),
+                my_players,
+                picks_until_next,
+                limit: 10,
+            },
+        )
+    }
+
+    fn ids(recommendations: &[Recommendation]) -> Vec<i32> {
+        recommendations
+            .iter()
+            .map(|recommendation| recommendation.player.id)
+            .collect()
+    }
+
+    #[test]
+    fn leaves_out_drafted_players_and_stops_at_the_limit() {
+        let mut players: Vec<PlayerResponse> = (1..=15)
+            .map(|id| ranked(id, Position::WR, id, None))
+            .collect();
+        players[0].drafted = true;
+
+        let recommended = ids(&recommendations(&players, &[], 11));
+        assert_eq!(recommended.len(), 10);
+        assert!(!recommended.contains(&1));
+        assert_eq!(recommended[0], 2);
+    }
+
+    #[test]
+    fn open_starting_slots_outrank_bench_only_players() {
+        let players = [
+            ranked(1, Position::QB, 1, None),
+            ranked(2, Position::RB, 5, None),
+        ];
+        let my_players = [my_player(99, Position::QB, None)];
+
+        assert_eq!(ids(&recommendations(&players, &my_players, 11)), vec![2, 1]);
+    }
+
+    #[test]
+    fn bye_weeks_shared_with_my_starters_move_a_player_down() {
+        let mut overlapping = ranked(1, Position::RB, 2, None);
+        overlapping.bye_week = Some(7);
+        let mut other = ranked(2, Position::RB, 3, None);
+        other.bye_week = Some(9);
+        let my_players = [my_player(99, Position::QB, Some(7))];
+
+        let recommendations = recommendations(&[overlapping, other], &my_players, 11);
+        assert_eq!(ids(&recommendations), vec![2, 1]);
+        assert_eq!(recommendations[1].score, recommendations[0].score + 1.0);
+        assert!(recommendations[1]
+            .explanation
+            .contains("week 7 bye overlaps 1 of your starters"));
+    }
+
+    #[test]
+    fn positions_that_drop_off_by_my_next_pick_move_up() {
+        let players = [
+            ranked(1, Position::WR, 1, None),
+            ranked(2, Position::RB, 2, Some(300.0)),
+            ranked(3, Position::RB, 3, Some(200.0)),
+        ];
+
+        let recommendations = recommendations(&players, &[], 0);
+        assert_eq!(recommendations[0].player.id, 2);
+        assert!(recommendations[0]
+            .explanation
+            .contains("projects 100.0 fewer points"));
+    }
+
+    #[test]
+    fn dropoff_assumes_other_teams_take_the_next_best_ranked() {
+        let players = [
+            ranked(1, Position::WR, 1, None),
+            ranked(2, Position::RB, 2, Some(300.0)),
+            ranked(3, Position::RB, 3, Some(200.0)),
+            ranked(4, Position::RB, 4, Some(150.0)),
+        ];
+        let explanation = |picks_until_next| {
+            recommendations(&players, &[], picks_until_next)
+                .into_iter()
+                .find(|recommendation| recommendation.player.id == 2)
+                .unwrap()
+                .explanation
+        };
+
+        // One pick only takes the receiver ranked ahead; two also take the
+        // next back.
+        assert!(explanation(1).contains("projects 100.0 fewer points"));
+        assert!(explanation(2).contains("projects 150.0 fewer points"));
+    }
+}
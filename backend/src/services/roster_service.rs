@@ -0,0 +1,244 @@
+use crate::models::players::Position;
+use crate::models::roster::{RosterPlayer, RosterSettings, RosterSlot, Slot};
+
+const DEDICATED_SLOTS: [(Slot, Position); 9] = [
+    (Slot::QB, Position::QB),
+    (Slot::RB, Position::RB),
+    (Slot::WR, Position::WR),
+    (Slot::TE, Position::TE),
+    (Slot::K, Position::K),
+    (Slot::DST, Position::DST),
+    (Slot::DL, Position::DL),
+    (Slot::LB, Position::LB),
+    (Slot::DB, Position::DB),
+];
+
+fn fits_flex(position: &Position) -> bool {
+    matches!(position, Position::RB | Position::WR | Position::TE)
+}
+
+fn fits_superflex(position: &Position) -> bool {
+    fits_flex(position) || *position == Position::QB
+}
+
+pub fn slot_fits(slot: &Slot, position: &Position) -> bool {
+    match slot {
+        Slot::FLEX => fits_flex(position),
+        Slot::SUPERFLEX => fits_superflex(position),
+        Slot::BENCH => true,
+        _ => DEDICATED_SLOTS
+            .iter()
+            .any(|(dedicated, dedicated_position)| {
+                dedicated == slot && dedicated_position == position
+            }),
+    }
+}
+
+// Takes the highest projected player `fits` allows out of `players`.
+fn take_best(
+    players: &mut Vec<RosterPlayer>,
+    fits: impl Fn(&Position) -> bool,
+) -> Option<RosterPlayer> {
+    let index = players
+        .iter()
+        .enumerate()
+        .filter(|(_, player)| fits(&player.position))
+        .max_by(|(_, a), (_, b)| {
+            a.projected_points
+                .unwrap_or(0.0)
+                .total_cmp(&b.projected_points.unwrap_or(0.0))
+        })
+        .map(|(index, _)| index)?;
+    Some(players.remove(index))
+}
+
+// Whether a player fits an open slot on the roster they were just added to,
+// i.e. whether adding them didn't leave anyone else without a slot.
+pub fn fits_open_slot(settings: &RosterSettings, players: &[RosterPlayer], player_id: i32) -> bool {
+    let without: Vec<RosterPlayer> = players
+        .iter()
+        .filter(|player| player.id != player_id)
+        .cloned()
+        .collect();
+    assign_slots(settings, players).1.len() <= assign_slots(settings, &without).1.len()
+}
+
+// Fills every slot in the roster, starters first. Each position's best
+// players take its own slots, then flex and superflex slots go to the best
+// players left who are eligible, and everyone else is benched. Since each
+// slot's eligible positions include the ones before it, that maximizes
+// projected starting points. Returns the slots in lineup order, and the
+// players that didn't fit anywhere.
+pub fn assign_slots(
+    settings: &RosterSettings,
+    players: &[RosterPlayer],
+) -> (Vec<RosterSlot>, Vec<RosterPlayer>) {
+    let mut remaining = players.to_vec();
+    let mut slots = Vec::new();
+
+    for (slot, position) in DEDICATED_SLOTS {
+        for _ in 0..settings.slots(&position) {
+            slots.push(RosterSlot {
+                slot: slot.clone(),
+                player: take_best(&mut remaining, |p| *p == position),
+            });
+        }
+    }
+    for _ in 0..settings.flex {
+        slots.push(RosterSlot {
+            slot: Slot::FLEX,
+            player: take_best(&mut remaining, fits_flex),
+        });
+    }
+    for _ in 0..settings.superflex {
+        slots.push(RosterSlot {
+            slot: Slot::SUPERFLEX,
+            player: take_best(&mut remaining, fits_superflex),
+        });
+    }
+    for _ in 0..settings.bench {
+        slots.push(RosterSlot {
+            slot: Slot::BENCH,
+            player: take_best(&mut remaining, |_| true),
+        });
+    }
+
+    slots.sort_by_key(|roster_slot| roster_slot.slot.clone());
+
+    (slots, remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::players::Team;
+
+    fn roster_player(id: i32, position: Position, projected_points: f64) -> RosterPlayer {
+        RosterPlayer {
+            id,
+            name: format!("Player {}", id),
+            position,
+            team: Team::KC,
+            bye_week: None,
+            projected_points: Some(projected_points),
+        }
+    }
+
+    fn settings(flex: i32, superflex: i32, bench: i32) -> RosterSettings {
+        RosterSettings {
+            qb: 1,
+            rb: 1,
+            wr: 1,
+            te: 0,
+            flex,
+            superflex,
+            k: 0,
+            dst: 0,
+            dl: 0,
+            lb: 0,
+            db: 0,
+            bench,
+        }
+    }
+
+    fn slot_players(slots: &[RosterSlot]) -> Vec<(Slot, Option<i32>)> {
+        slots
+            .iter()
+            .map(|roster_slot| {
+                (
+                    roster_slot.slot.clone(),
+                    roster_slot.player.as_ref().map(|player| player.id),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn best_players_start_and_the_next_best_takes_the_flex() {
+        let players = [
+            roster_player(1, Position::RB, 150.0),
+            roster_player(2, Position::RB, 200.0),
+            roster_player(3, Position::WR, 180.0),
+            roster_player(4, Position::WR, 140.0),
+            roster_player(5, Position::QB, 300.0),
+        ];
+        let (slots, unassigned) = assign_slots(&settings(1, 0, 1), &players);
+
+        assert_eq!(
+            slot_players(&slots),
+            vec![
+                (Slot::QB, Some(5)),
+                (Slot::RB, Some(2)),
+                (Slot::WR, Some(3)),
+                (Slot::FLEX, Some(1)),
+                (Slot::BENCH, Some(4)),
+            ]
+        );
+        assert!(unassigned.is_empty());
+    }
+
+    #[test]
+    fn flex_never_takes_a_quarterback_but_superflex_does() {
+        let players = [
+            roster_player(1, Position::QB, 300.0),
+            roster_player(2, Position::QB, 250.0),
+            roster_player(3, Position::WR, 100.0),
+        ];
+        let (slots, _) = assign_slots(&settings(1, 1, 0), &players);
+
+        assert_eq!(
+            slot_players(&slots),
+            vec![
+                (Slot::QB, Some(1)),
+                (Slot::RB, None),
+                (Slot::WR, Some(3)),
+                (Slot::FLEX, None),
+                (Slot::SUPERFLEX, Some(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn players_past_the_bench_are_unassigned() {
+        let players = [
+            roster_player(1, Position::QB, 300.0),
+            roster_player(2, Position::QB, 250.0),
+            roster_player(3, Position::QB, 200.0),
+        ];
+        let (slots, unassigned) = assign_slots(&settings(0, 0, 1), &players);
+
+        assert_eq!(slots.last().unwrap().player.as_ref().unwrap().id, 2);
+        assert_eq!(unassigned.len(), 1);
+        assert_eq!(unassigned[0].id, 3);
+    }
+
+    #[test]
+    fn players_without_projections_sit_behind_projected_ones() {
+        let mut unprojected = roster_player(1, Position::RB, 0.0);
+        unprojected.projected_points = None;
+        let players = [unprojected, roster_player(2, Position::RB, 10.0)];
+        let (slots, _) = assign_slots(&settings(0, 0, 1), &players);
+
+        assert_eq!(
+            slot_players(&slots),
+            vec![
+                (Slot::QB, None),
+                (Slot::RB, Some(2)),
+                (Slot::WR, None),
+                (Slot::BENCH, Some(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn fits_open_slot_is_false_once_the_roster_overflows() {
+        let players = [
+            roster_player(1, Position::QB, 300.0),
+            roster_player(2, Position::QB, 250.0),
+        ];
+        let settings = settings(0, 0, 0);
+
+        assert!(fits_open_slot(&settings, &players[..1], 1));
+        assert!(!fits_open_slot(&settings, &players, 2));
+    }
+}
@@ -0,0 +1,189 @@
+use round::round;
+
+use crate::models::players::Position;
+use crate::models::rankings::ScoringSettings;
+use crate::models::scoring_profiles::{ScoringRules, ScoringTier};
+use crate::models::stats::{StatsBase, StatsResponse};
+
+// Built-in rules matching the FantasyPros scoring each ranking set is built on.
+pub fn preset(scoring_settings: &ScoringSettings) -> ScoringRules {
+    let receptions = match scoring_settings {
+        ScoringSettings::Standard => 0.0,
+        ScoringSettings::Half => 0.5,
+        ScoringSettings::PPR => 1.0,
+    };
+
+    ScoringRules {
+        weights: StatsBase {
+            pass_yds: 0.04,
+            pass_td: 4.0,
+            pass_int: -2.0,
+            rush_yds: 0.1,
+            rush_td: 6.0,
+            fumbles: -2.0,
+            receptions,
+            rec_yds: 0.1,
+            rec_td: 6.0,
+            fg_1_19: 3.0,
+            fg_20_29: 3.0,
+            fg_30_39: 3.0,
+            fg_40_49: 4.0,
+            fg_50: 5.0,
+            extra_points: 1.0,
+            sacks: 1.0,
+            int: 2.0,
+            fumbles_recovered: 2.0,
+            def_td: 6.0,
+            safeties: 2.0,
+            special_teams_td: 6.0,
+            tackles: 1.0,
+            assists: 0.5,
+            passes_defended: 1.0,
+            idp_sacks: 2.0,
+            idp_int: 2.0,
+            idp_fumbles_forced: 2.0,
+            idp_fumbles_recovered: 2.0,
+            idp_td: 6.0,
+            ..Default::default()
+        },
+        points_allowed_tiers: [
+            (Some(0.0), 10.0),
+            (Some(6.0), 7.0),
+            (Some(13.0), 4.0),
+            (Some(20.0), 1.0),
+            (Some(27.0), 0.0),
+            (Some(34.0), -1.0),
+            (None, -4.0),
+        ]
+        .into_iter()
+        .map(|(up_to, points)| ScoringTier { up_to, points })
+        .collect(),
+        yards_allowed_tiers: [
+            (Some(99.0), 5.0),
+            (Some(199.0), 3.0),
+            (Some(299.0), 2.0),
+            (Some(349.0), 0.0),
+            (Some(399.0), -1.0),
+            (Some(449.0), -3.0),
+            (Some(499.0), -5.0),
+            (Some(549.0), -6.0),
+            (None, -7.0),
+        ]
+        .into_iter()
+        .map(|(up_to, points)| ScoringTier { up_to, points })
+        .collect(),
+    }
+}
+
+pub fn calculate_points(stats: &StatsBase, position: &Position, rules: &ScoringRules) -> f64 {
+    let weights = &rules.weights;
+
+    macro_rules! weighted_sum {
+        ($($field:ident),*) => {
+            0.0 $(+ stats.$field * weights.$field)*
+        }
+    }
+
+    weighted_sum!(
+        pass_cmp,
+        pass_att,
+        pass_cmp_pct,
+        pass_yds,
+        pass_yds_per_att,
+        pass_td,
+        pass_int,
+        pass_sacks,
+        rush_att,
+        rush_yds,
+        rush_yds_per_att,
+        rush_long,
+        rush_20,
+        rush_td,
+        fumbles,
+        receptions,
+        rec_tgt,
+        rec_tgt_pct,
+        rec_yds,
+        rec_yds_per_rec,
+        rec_long,
+        rec_20,
+        rec_td,
+        fg_att,
+        fg_pct,
+        fg_long,
+        extra_points,
+        xp_att,
+        sacks,
+        int,
+        fumbles_recovered,
+        fumbles_forced,
+        def_td,
+        safeties,
+        special_teams_td,
+        points_allowed,
+        yards_allowed,
+        tackles,
+        assists,
+        tackles_for_loss,
+        qb_hits,
+        passes_defended,
+        idp_sacks,
+        idp_int,
+        idp_fumbles_forced,
+        idp_fumbles_recovered,
+        idp_td,
+        games
+    ) + field_goal_points(stats, rules)
+        + tier_points(stats, position, rules)
+}
+
+// Field goals made are scored from the distance buckets when the line has
+// them, and from the total otherwise (projections only give the total). A
+// total without a weight of its own is scored at the 30-39 yard value.
+fn field_goal_points(stats: &StatsBase, rules: &ScoringRules) -> f64 {
+    let weights = &rules.weights;
+    let buckets = [
+        (stats.fg_1_19, weights.fg_1_19),
+        (stats.fg_20_29, weights.fg_20_29),
+        (stats.fg_30_39, weights.fg_30_39),
+        (stats.fg_40_49, weights.fg_40_49),
+        (stats.fg_50, weights.fg_50),
+    ];
+
+    if buckets.iter().any(|(made, _)| *made > 0.0) {
+        buckets.iter().map(|(made, weight)| made * weight).sum()
+    } else if weights.field_goals != 0.0 {
+        stats.field_goals * weights.field_goals
+    } else {
+        stats.field_goals * weights.fg_30_39
+    }
+}
+
+// Tiers are per-game brackets, so a season line is scored at its per-game
+// average and multiplied back out by games played.
+fn tier_points(stats: &StatsBase, position: &Position, rules: &ScoringRules) -> f64 {
+    if !matches!(position, Position::DST) || stats.games <= 0.0 {
+        return 0.0;
+    }
+
+    let per_game = |total: f64, tiers: &[ScoringTier]| {
+        tiers
+            .iter()
+            .find(|tier| tier.up_to.is_none_or(|up_to| total / stats.games <= up_to))
+            .map_or(0.0, |tier| tier.points)
+    };
+
+    (per_game(stats.points_allowed, &rules.points_allowed_tiers)
+        + per_game(stats.yards_allowed, &rules.yards_allowed_tiers))
+        * stats.games
+}
+
+// Fills in `points`/`points_per_game` for a stat line. Players without any
+// games played are left unscored so the board shows them as blank.
+pub fn apply_scoring(stats: &mut StatsResponse, position: &Position, rules: &ScoringRules) {
+    if stats.base.games > 0.0 {
+        let points = calculate_points(&stats.base, position, rules);
+        stats.points = Some(points);
+        stats.points_per_game = Some(round(points / stats.base.games, 1));
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use crate::models::players::{PlayerResponse, Position};
+
+pub const DEFAULT_TIER_SENSITIVITY: f64 = 1.0;
+
+// Tier numbers for players already sorted by consensus average, as indexes
+// into `players`. A new tier starts wherever the gap to the next player's
+// average is larger than the two players' typical spread (the mean of their
+// standard deviations) divided by `sensitivity`, so raising the sensitivity
+// splits the board into more tiers.
+fn cluster(players: &[&PlayerResponse], sensitivity: f64) -> Vec<i32> {
+    let mut tiers = Vec::with_capacity(players.len());
+    let mut tier = 1;
+    for (index, player) in players.iter().enumerate() {
+        if let Some(previous) = index.checked_sub(1).map(|index| players[index]) {
+            let gap = (player.rankings.average - previous.rankings.average) as f64;
+            let spread = (player.rankings.standard_deviation + previous.rankings.standard_deviation)
+                as f64
+                / 2.0;
+            if gap * sensitivity > spread {
+                tier += 1;
+            }
+        }
+        tiers.push(tier);
+    }
+    tiers
+}
+
+// Fills in each player's positional `tier` and `overall_tier` from the
+// consensus average and standard deviation in their rankings.
+pub fn apply_tiers(players: &mut [PlayerResponse], sensitivity: f64) {
+    let mut order: Vec<usize> = (0..players.len()).collect();
+    order.sort_by(|a, b| {
+        players[*a]
+            .rankings
+            .average
+            .total_cmp(&players[*b].rankings.average)
+    });
+
+    let overall_tiers = {
+        let sorted: Vec<&PlayerResponse> = order.iter().map(|index| &players[*index]).collect();
+        cluster(&sorted, sensitivity)
+    };
+
+    let mut by_position: HashMap<Position, Vec<usize>> = HashMap::new();
+    for index in &order {
+        by_position
+            .entry(players[*index].position.clone())
+            .or_default()
+            .push(*index);
+    }
+    let mut tiers: Vec<(usize, i32)> = Vec::with_capacity(players.len());
+    for indexes in by_position.values() {
+        let sorted: Vec<&PlayerResponse> = indexes.iter().map(|index| &players[*index]).collect();
+        tiers.extend(indexes.iter().copied().zip(cluster(&sorted, sensitivity)));
+    }
+
+    for (index, overall_tier) in order.into_iter().zip(overall_tiers) {
+        players[index].rankings.overall_tier = Some(overall_tier);
+    }
+    for (index, tier) in tiers {
+        players[index].rankings.tier = Some(tier);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::players::Team;
+    use crate::models::rankings::RankingsBase;
+    use crate::models::stats::{StatsBase, StatsResponse};
+
+    fn no_points() -> StatsResponse {
+        StatsResponse {
+            season: None,
+            base: StatsBase::default(),
+            points: None,
+            points_per_game: None,
+            spike_weeks: None,
+            best_ball_points: None,
+        }
+    }
+
+    // Only the consensus average and spread feed tiers.
+    fn tiered(
+        id: i32,
+        position: Position,
+        average: f32,
+        standard_deviation: f32,
+    ) -> PlayerResponse {
+        PlayerResponse {
+            id,
+            name: format!("Player {}", id),
+            position,
+            team: Team::KC,
+            bye_week: None,
+            height: String::new(),
+            weight: String::new(),
+            age: None,
+            college: String::new(),
+            rankings: RankingsBase {
+                overall: id,
+                position: id,
+                best: id,
+                worst: id,
+                average,
+                standard_deviation,
+                tier: None,
+                overall_tier: None,
+            },
+            stats: no_points(),
+            projections: no_points(),
+            drafted: false,
+            drafted_by_me: false,
+            keeper_round: None,
+            keeper_value: None,
+            auction_value: None,
+            vorp: None,
+            vbd_rank: None,
+        }
+    }
+
+    // Receivers with these (average, standard deviation) pairs, already in
+    // average order.
+    fn cluster_rankings(rankings: &[(f32, f32)], sensitivity: f64) -> Vec<i32> {
+        let players: Vec<PlayerResponse> = rankings
+            .iter()
+            .enumerate()
+            .map(|(index, (average, standard_deviation))| {
+                tiered(
+                    index as i32 + 1,
+                    Position::WR,
+                    *average,
+                    *standard_deviation,
+                )
+            })
+            .collect();
+        let sorted: Vec<&PlayerResponse> = players.iter().collect();
+        cluster(&sorted, sensitivity)
+    }
+
+    #[test]
+    fn splits_where_the_gap_beats_the_spread() {
+        assert_eq!(
+            cluster_rankings(
+                &[(1.0, 1.0), (2.0, 1.0), (3.0, 1.0), (10.0, 1.0), (11.0, 1.0)],
+                1.0
+            ),
+            vec![1, 1, 1, 2, 2]
+        );
+    }
+
+    #[test]
+    fn a_gap_equal_to_the_spread_stays_in_the_tier() {
+        assert_eq!(
+            cluster_rankings(&[(1.0, 2.0), (3.0, 2.0), (5.5, 2.0)], 1.0),
+            vec![1, 1, 2]
+        );
+        // Tied averages never split, even with no spread at all.
+        assert_eq!(
+            cluster_rankings(&[(4.0, 0.0), (4.0, 0.0), (4.5, 0.0)], 1.0),
+            vec![1, 1, 2]
+        );
+    }
+
+    #[test]
+    fn spread_is_the_mean_of_both_players_deviations() {
+        // A gap of 2 against spreads of (1 + 4) / 2 = 2.5, then (4 + 0) / 2 = 2.
+        assert_eq!(
+            cluster_rankings(&[(1.0, 1.0), (3.0, 4.0), (5.0, 0.0), (7.5, 0.0)], 1.0),
+            vec![1, 1, 1, 2]
+        );
+    }
+
+    #[test]
+    fn higher_sensitivity_splits_into_more_tiers() {
+        let rankings = [(1.0, 1.0), (2.0, 1.0), (3.0, 1.0), (10.0, 1.0), (11.0, 1.0)];
+
+        assert_eq!(cluster_rankings(&rankings, 2.0), vec![1, 2, 3, 4, 5]);
+        assert_eq!(cluster_rankings(&rankings, 0.1), vec![1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn positional_tiers_only_compare_players_at_the_position() {
+        // Given out of average order, since boards aren't sorted by it.
+        let mut players = vec![
+            tiered(1, Position::QB, 3.0, 1.0),
+            tiered(2, Position::RB, 1.5, 1.0),
+            tiered(3, Position::RB, 2.0, 1.0),
+            tiered(4, Position::RB, 2.5, 1.0),
+            tiered(5, Position::QB, 1.0, 1.0),
+        ];
+        apply_tiers(&mut players, 1.0);
+
+        let tiers: Vec<(Option<i32>, Option<i32>)> = players
+            .iter()
+            .map(|player| (player.rankings.tier, player.rankings.overall_tier))
+            .collect();
+        // Overall, every gap is within the spread. The two QBs are two spots
+        // apart once the RBs between them are left out.
+        assert_eq!(
+            tiers,
+            vec![
+                (Some(2), Some(1)),
+                (Some(1), Some(1)),
+                (Some(1), Some(1)),
+                (Some(1), Some(1)),
+                (Some(1), Some(1)),
+            ]
+        );
+    }
+}
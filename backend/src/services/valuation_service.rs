@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+
+use crate::models::players::{PlayerResponse, PointsSource};
+use crate::models::roster::RosterSettings;
+use crate::services::vbd_service;
+
+pub const DEFAULT_BUDGET: i32 = 200;
+pub const DEFAULT_TEAM_COUNT: i32 = 12;
+
+// Money still in an auction, used to re-inflate the values of players left on
+// the board.
+pub struct AuctionPool {
+    pub remaining_budget: i32,
+    pub open_slots: i32,
+}
+
+pub struct ValuationSettings<'a> {
+    pub budget: i32,
+    pub team_count: i32,
+    pub roster: &'a RosterSettings,
+    pub auction: Option<AuctionPool>,
+}
+
+// Fills in `auction_value` from each player's projected points. Every rostered
+// player costs at least $1, and the rest of the league's money is split by
+// surplus points over the best player left once every starting slot is
+// filled. During an auction, players still on the board share whatever money
+// is left instead.
+pub fn apply_auction_values(players: &mut [PlayerResponse], settings: &ValuationSettings) {
+    let surplus = surplus_points(players, settings);
+    let total_surplus: f64 = surplus.values().sum();
+    if total_surplus <= 0.0 {
+        return;
+    }
+
+    // A budget that can't cover $1 per roster spot leaves nothing to split, so
+    // every player goes for the minimum bid.
+    let spare_money = (settings.team_count * (settings.budget - settings.roster.size())).max(0);
+    let dollars_per_point = spare_money as f64 / total_surplus;
+    let mut values: HashMap<i32, f64> = surplus
+        .iter()
+        .map(|(player_id, surplus)| (*player_id, surplus * dollars_per_point))
+        .collect();
+
+    if let Some(auction) = &settings.auction {
+        let undrafted_value: f64 = players
+            .iter()
+            .filter(|player| !player.drafted)
+            .filter_map(|player| values.get(&player.id))
+            .sum();
+        if undrafted_value > 0.0 {
+            let inflation =
+                (auction.remaining_budget - auction.open_slots).max(0) as f64 / undrafted_value;
+            for player in players.iter().filter(|player| !player.drafted) {
+                if let Some(value) = values.get_mut(&player.id) {
+                    *value *= inflation;
+                }
+            }
+        }
+    }
+
+    for player in players.iter_mut() {
+        if player.projections.points.is_some() {
+            let value = values.get(&player.id).copied().unwrap_or(0.0);
+            player.auction_value = Some((1 + value.round() as i32).max(1));
+        }
+    }
+}
+
+// Projected points above replacement for every player who'd start on some
+// team, where replacement is the best player left at the position once every
+// starting slot is filled.
+fn surplus_points(players: &[PlayerResponse], settings: &ValuationSettings) -> HashMap<i32, f64> {
+    let by_position = vbd_service::ranked_by_position(players, PointsSource::Projections);
+    let starters = vbd_service::starter_counts(&by_position, settings.roster, settings.team_count);
+
+    let mut surplus = HashMap::new();
+    for (position, ranked) in &by_position {
+        let taken = starters.get(position).copied().unwrap_or(0);
+        if taken == 0 {
+            continue;
+        }
+        let replacement = ranked.get(taken).map_or(0.0, |(_, points)| *points);
+        for (player_id, points) in &ranked[..taken] {
+            surplus.insert(*player_id, (points - replacement).max(0.0));
+        }
+    }
+
+    surplus
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::players::{Position, Team};
+    use crate::models::rankings::RankingsBase;
+    use crate::models::stats::{StatsBase, StatsResponse};
+
+    fn projection(points: Option<f64>) -> StatsResponse {
+        StatsResponse {
+            season: None,
+            base: StatsBase::default(),
+            points,
+            points_per_game: None,
+            spike_weeks: None,
+            best_ball_points: None,
+        }
+    }
+
+    // Only the position and projected points feed auction values.
+    fn projected(id: i32, position: Position, points: Option<f64>) -> PlayerResponse {
+        PlayerResponse {
+            id,
+            name: format!("Player {}", id),
+            position,
+            team: Team::KC,
+            bye_week: None,
+            height: String::new(),
+            weight: String::new(),
+            age: None,
+            college: String::new(),
+            rankings: RankingsBase {
+                overall: id,
+                position: id,
+                best: id,
+                worst: id,
+                average: id as f32,
+                standard_deviation: 0.0,
+                tier: None,
+                overall_tier: None,
+            },
+            stats: projection(None),
+            projections: projection(points),
+            drafted: false,
+            drafted_by_me: false,
+            keeper_round: None,
+            keeper_value: None,
+            auction_value: None,
+            vorp: None,
+            vbd_rank: None,
+        }
+    }
+
+    fn roster(qb: i32, rb: i32, wr: i32, flex: i32) -> RosterSettings {
+        RosterSettings {
+            qb,
+            rb,
+            wr,
+            te: 0,
+            flex,
+            superflex: 0,
+            k: 0,
+            dst: 0,
+            dl: 0,
+            lb: 0,
+            db: 0,
+            bench: 0,
+        }
+    }
+
+    fn quarterbacks() -> Vec<PlayerResponse> {
+        vec![
+            projected(1, Position::QB, Some(300.0)),
+            projected(2, Position::QB, Some(250.0)),
+            projected(3, Position::QB, Some(200.0)),
+            projected(4, Position::QB, Some(150.0)),
+        ]
+    }
+
+    fn values(players: &[PlayerResponse]) -> Vec<Option<i32>> {
+        players.iter().map(|player| player.auction_value).collect()
+    }
+
+    #[test]
+    fn starters_split_the_league_budget_by_surplus_points() {
+        let roster = roster(1, 0, 0, 0);
+        let mut players = quarterbacks();
+        apply_auction_values(
+            &mut players,
+            &ValuationSettings {
+                budget: 10,
+                team_count: 2,
+                roster: &roster,
+                auction: None,
+            },
+        );
+
+        // $18 over the $1 minimums, split 100:50 between the two starters.
+        assert_eq!(values(&players), vec![Some(13), Some(7), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn flex_slots_go_to_the_best_player_left() {
+        let roster = roster(0, 1, 1, 1);
+        let mut players = vec![
+            projected(1, Position::RB, Some(200.0)),
+            projected(2, Position::RB, Some(150.0)),
+            projected(3, Position::RB, Some(100.0)),
+            projected(4, Position::WR, Some(180.0)),
+            projected(5, Position::WR, Some(90.0)),
+        ];
+        apply_auction_values(
+            &mut players,
+            &ValuationSettings {
+                budget: 27,
+                team_count: 1,
+                roster: &roster,
+                auction: None,
+            },
+        );
+
+        // The second back takes the flex over the second receiver, so backs
+        // are measured against the third back and receivers against the
+        // second receiver: $24 over 100 + 50 + 90 surplus points.
+        assert_eq!(
+            values(&players),
+            vec![Some(11), Some(6), Some(1), Some(10), Some(1)]
+        );
+    }
+
+    #[test]
+    fn superflex_slots_start_a_second_quarterback() {
+        let mut roster = roster(1, 0, 0, 0);
+        roster.superflex = 1;
+        let mut players = vec![
+            projected(1, Position::QB, Some(300.0)),
+            projected(2, Position::QB, Some(250.0)),
+            projected(3, Position::QB, Some(200.0)),
+        ];
+        apply_auction_values(
+            &mut players,
+            &ValuationSettings {
+                budget: 17,
+                team_count: 1,
+                roster: &roster,
+                auction: None,
+            },
+        );
+
+        // Both quarterbacks start, measured against the third.
+        assert_eq!(values(&players), vec![Some(11), Some(6), Some(1)]);
+    }
+
+    #[test]
+    fn players_without_projections_get_no_value() {
+        let roster = roster(1, 0, 0, 0);
+        let mut players = quarterbacks();
+        players.push(projected(5, Position::QB, None));
+        apply_auction_values(
+            &mut players,
+            &ValuationSettings {
+                budget: 10,
+                team_count: 2,
+                roster: &roster,
+                auction: None,
+            },
+        );
+
+        assert_eq!(players[4].auction_value, None);
+    }
+
+    #[test]
+    fn remaining_money_is_shared_by_players_left_on_the_board() {
+        let roster = roster(1, 0, 0, 0);
+        let mut players = quarterbacks();
+        players[0].drafted = true;
+        apply_auction_values(
+            &mut players,
+            &ValuationSettings {
+                budget: 10,
+                team_count: 2,
+                roster: &roster,
+                auction: Some(AuctionPool {
+                    remaining_budget: 10,
+                    open_slots: 1,
+                }),
+            },
+        );
+
+        // The $9 left over the last slot's $1 all goes to the one starter left.
+        assert_eq!(values(&players), vec![Some(13), Some(10), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn budgets_below_the_roster_size_give_minimum_bids() {
+        let roster = roster(1, 1, 1, 0);
+        let mut players = quarterbacks();
+        apply_auction_values(
+            &mut players,
+            &ValuationSettings {
+                budget: 2,
+                team_count: 2,
+                roster: &roster,
+                auction: None,
+            },
+        );
+
+        assert_eq!(values(&players), vec![Some(1), Some(1), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn starters_tied_with_replacement_have_no_surplus() {
+        let roster = roster(1, 0, 0, 0);
+        let mut players = vec![
+            projected(1, Position::QB, Some(200.0)),
+            projected(2, Position::QB, Some(200.0)),
+            projected(3, Position::QB, Some(200.0)),
+        ];
+        apply_auction_values(
+            &mut players,
+            &ValuationSettings {
+                budget: 10,
+                team_count: 2,
+                roster: &roster,
+                auction: None,
+            },
+        );
+
+        assert_eq!(values(&players), vec![None, None, None]);
+    }
+}
@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+
+use round::round;
+
+use crate::models::players::{BaselineMethod, PlayerResponse, PointsSource, Position};
+use crate::models::roster::RosterSettings;
+
+pub struct VbdSettings<'a> {
+    pub team_count: i32,
+    pub roster: &'a RosterSettings,
+    pub method: BaselineMethod,
+    pub source: PointsSource,
+    // Replacement rank per position for `BaselineMethod::Manual`.
+    pub manual_baselines: &'a HashMap<Position, usize>,
+}
+
+fn points(player: &PlayerResponse, source: PointsSource) -> Option<f64> {
+    match source {
+        PointsSource::Projections => player.projections.points,
+        PointsSource::Stats => player.stats.points,
+    }
+}
+
+// (player id, points) per position, best first. Players without points are
+// left out.
+pub fn ranked_by_position(
+    players: &[PlayerResponse],
+    source: PointsSource,
+) -> HashMap<Position, Vec<(i32, f64)>> {
+    let mut by_position: HashMap<Position, Vec<(i32, f64)>> = HashMap::new();
+    for player in players {
+        if let Some(points) = points(player, source) {
+            by_position
+                .entry(player.position.clone())
+                .or_default()
+                .push((player.id, points));
+        }
+    }
+    for ranked in by_position.values_mut() {
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    }
+    by_position
+}
+
+// Players per position who start on some team. Flex slots go to the best
+// running backs, receivers and tight ends left after their own slots are
+// filled, then superflex slots to the best of those and the quarterbacks.
+pub fn starter_counts(
+    by_position: &HashMap<Position, Vec<(i32, f64)>>,
+    roster: &RosterSettings,
+    team_count: i32,
+) -> HashMap<Position, usize> {
+    let mut starters: HashMap<Position, usize> = by_position
+        .iter()
+        .map(|(position, ranked)| {
+            let slots = (roster.slots(position) * team_count) as usize;
+            (position.clone(), slots.min(ranked.len()))
+        })
+        .collect();
+
+    let flex_slots = [
+        (roster.flex, vec![Position::RB, Position::WR, Position::TE]),
+        (
+            roster.superflex,
+            vec![Position::QB, Position::RB, Position::WR, Position::TE],
+        ),
+    ];
+    for (slots, eligible) in flex_slots {
+        for _ in 0..slots * team_count {
+            let best_flex = eligible
+                .iter()
+                .filter_map(|position| {
+                    let taken = *starters.get(position)?;
+                    let (_, points) = by_position.get(position)?.get(taken)?;
+                    Some((position.clone(), *points))
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            match best_flex {
+                Some((position, _)) => *starters.entry(position).or_default() += 1,
+                None => break,
+            }
+        }
+    }
+
+    starters
+}
+
+// Positional rank (1-based) of the replacement player at each position.
+// Bench spots are shared out in proportion to each position's starters.
+fn baseline_ranks(
+    by_position: &HashMap<Position, Vec<(i32, f64)>>,
+    settings: &VbdSettings,
+) -> HashMap<Position, usize> {
+    let starters = starter_counts(by_position, settings.roster, settings.team_count);
+    let total_starters: usize = starters.values().sum();
+    let bench_spots = (settings.roster.bench * settings.team_count).max(0) as f64;
+
+    starters
+        .iter()
+        .map(|(position, starters)| {
+            let rank = match settings.method {
+                BaselineMethod::Manual if settings.manual_baselines.contains_key(position) => {
+                    settings.manual_baselines[position]
+                }
+                BaselineMethod::StarterPlusBench if total_starters > 0 => {
+                    let bench = bench_spots * *starters as f64 / total_starters as f64;
+                    starters + bench.round() as usize
+                }
+                _ => *starters,
+            };
+            (position.clone(), rank)
+        })
+        .collect()
+}
+
+// Fills in `vorp` (points over the replacement player at the same position)
+// and `vbd_rank` (overall rank by VORP). Positions without a starting slot
+// have no replacement player, so their players get neither.
+pub fn apply_vbd(players: &mut [PlayerResponse], settings: &VbdSettings) {
+    let by_position = ranked_by_position(players, settings.source);
+    let baselines: HashMap<Position, f64> = baseline_ranks(&by_position, settings)
+        .into_iter()
+        .filter(|(_, rank)| *rank > 0)
+        .filter_map(|(position, rank)| {
+            let ranked = by_position.get(&position)?;
+            let (_, points) = ranked.get(rank - 1).or(ranked.last())?;
+            Some((position, *points))
+        })
+        .collect();
+
+    for player in players.iter_mut() {
+        player.vorp = points(player, settings.source)
+            .zip(baselines.get(&player.position))
+            .map(|(points, baseline)| round(points - baseline, 1));
+    }
+
+    let mut by_vorp: Vec<(usize, f64)> = players
+        .iter()
+        .enumerate()
+        .filter_map(|(index, player)| Some((index, player.vorp?)))
+        .collect();
+    by_vorp.sort_by(|a, b| b.1.total_cmp(&a.1));
+    for (rank, (index, _)) in by_vorp.into_iter().enumerate() {
+        players[index].vbd_rank = Some(rank as i32 + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::players::Team;
+    use crate::models::rankings::RankingsBase;
+    use crate::models::stats::{StatsBase, StatsResponse};
+
+    fn stat_line(points: Option<f64>) -> StatsResponse {
+        StatsResponse {
+            season: None,
+            base: StatsBase::default(),
+            points,
+            points_per_game: None,
+            spike_weeks: None,
+            best_ball_points: None,
+        }
+    }
+
+    // A player with last season's points and this season's projection.
+    fn scored(
+        id: i32,
+        position: Position,
+        stats: Option<f64>,
+        projected: Option<f64>,
+    ) -> PlayerResponse {
+        PlayerResponse {
+            id,
+            name: format!("Player {}", id),
+            position,
+            team: Team::KC,
+            bye_week: None,
+            height: String::new(),
+            weight: String::new(),
+            age: None,
+            college: String::new(),
+            rankings: RankingsBase {
+                overall: id,
+                position: id,
+                best: id,
+                worst: id,
+                average: id as f32,
+                standard_deviation: 0.0,
+                tier: None,
+                overall_tier: None,
+            },
+            stats: stat_line(stats),
+            projections: stat_line(projected),
+            drafted: false,
+            drafted_by_me: false,
+            keeper_round: None,
+            keeper_value: None,
+            auction_value: None,
+            vorp: None,
+            vbd_rank: None,
+        }
+    }
+
+    // One QB and one RB per team plus a flex, across two teams.
+    fn roster() -> RosterSettings {
+        RosterSettings {
+            qb: 1,
+            rb: 1,
+            wr: 0,
+            te: 0,
+            flex: 1,
+            superflex: 0,
+            k: 0,
+            dst: 0,
+            dl: 0,
+            lb: 0,
+            db: 0,
+            bench: 2,
+        }
+    }
+
+    fn players() -> Vec<PlayerResponse> {
+        [
+            (Position::QB, 300.0),
+            (Position::QB, 280.0),
+            (Position::QB, 260.0),
+            (Position::RB, 200.0),
+            (Position::RB, 180.0),
+            (Position::RB, 160.0),
+            (Position::RB, 140.0),
+            (Position::WR, 170.0),
+            (Position::WR, 150.0),
+            (Position::K, 120.0),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (position, points))| scored(index as i32 + 1, position, None, Some(points)))
+        .collect()
+    }
+
+    fn apply(
+        players: &mut [PlayerResponse],
+        method: BaselineMethod,
+        source: PointsSource,
+        manual_baselines: &HashMap<Position, usize>,
+    ) {
+        let roster = roster();
+        apply_vbd(
+            players,
+            &VbdSettings {
+                team_count: 2,
+                roster: &roster,
+                method,
+                source,
+                manual_baselines,
+            },
+        );
+    }
+
+    fn vorp(
+        method: BaselineMethod,
+        manual_baselines: &HashMap<Position, usize>,
+    ) -> Vec<(i32, Option<f64>)> {
+        let mut players = players();
+        apply(
+            &mut players,
+            method,
+            PointsSource::Projections,
+            manual_baselines,
+        );
+        players
+            .iter()
+            .map(|player| (player.id, player.vorp))
+            .collect()
+    }
+
+    #[test]
+    fn flex_starters_go_to_the_best_players_left() {
+        let by_position = ranked_by_position(&players(), PointsSource::Projections);
+        let starters = starter_counts(&by_position, &roster(), 2);
+
+        assert_eq!(starters[&Position::QB], 2);
+        // The first flex goes to the 170-point WR over the 160-point RB, the
+        // second to that RB over the 150-point WR.
+        assert_eq!(starters[&Position::RB], 3);
+        assert_eq!(starters[&Position::WR], 1);
+        assert_eq!(starters[&Position::K], 0);
+    }
+
+    #[test]
+    fn last_starter_baseline() {
+        let vorp = vorp(BaselineMethod::LastStarter, &HashMap::new());
+
+        assert_eq!(vorp[0], (1, Some(20.0)));
+        assert_eq!(vorp[3], (4, Some(40.0)));
+        assert_eq!(vorp[6], (7, Some(-20.0)));
+        assert_eq!(vorp[7], (8, Some(0.0)));
+    }
+
+    #[test]
+    fn starter_plus_bench_baseline_shares_bench_spots_by_starters() {
+        let vorp = vorp(BaselineMethod::StarterPlusBench, &HashMap::new());
+
+        // Four bench spots over six starters: one more QB, two more RBs (past
+        // the last one, so the last one is used) and one more WR.
+        assert_eq!(vorp[0], (1, Some(40.0)));
+        assert_eq!(vorp[3], (4, Some(60.0)));
+        assert_eq!(vorp[7], (8, Some(20.0)));
+    }
+
+    #[test]
+    fn manual_baseline_falls_back_to_the_last_starter() {
+        let manual_baselines = HashMap::from([(Position::QB, 1)]);
+        let vorp = vorp(BaselineMethod::Manual, &manual_baselines);
+
+        assert_eq!(vorp[0], (1, Some(0.0)));
+        assert_eq!(vorp[3], (4, Some(40.0)));
+    }
+
+    #[test]
+    fn positions_without_a_starting_slot_get_no_vorp() {
+        let vorp = vorp(BaselineMethod::LastStarter, &HashMap::new());
+
+        assert_eq!(vorp[9], (10, None));
+    }
+
+    #[test]
+    fn stats_source_ignores_projections() {
+        // Only the second QB has last season's points, so under stats they're
+        // the lone starter and their own replacement, while the projected
+        // QBs are left out.
+        let mut players = players();
+        players[1].stats.points = Some(250.0);
+        apply(
+            &mut players,
+            BaselineMethod::LastStarter,
+            PointsSource::Stats,
+            &HashMap::new(),
+        );
+
+        assert_eq!(players[1].vorp, Some(0.0));
+        assert_eq!(players[1].vbd_rank, Some(1));
+        assert_eq!(players[0].vorp, None);
+        assert_eq!(players[3].vorp, None);
+    }
+
+    #[test]
+    fn vbd_rank_orders_by_vorp() {
+        let mut players = players();
+        apply(
+            &mut players,
+            BaselineMethod::LastStarter,
+            PointsSource::Projections,
+            &HashMap::new(),
+        );
+
+        assert_eq!(players[3].vbd_rank, Some(1));
+        assert_eq!(players[0].vbd_rank, Some(2));
+        assert_eq!(players[9].vbd_rank, None);
+    }
+}
@@ -90,7 +90,51 @@ export const POSITION_STATS: Record<Position, StatHeader[]> = {
 		{ key: 'fumbles_forced', label: 'FF' },
 		{ key: 'def_td', label: 'DTD' },
 		{ key: 'safeties', label: 'SAFETY' },
-		{ key: 'special_teams_td', label: 'STTD' }
+		{ key: 'special_teams_td', label: 'STTD' },
+		{ key: 'points_allowed', label: 'PA' },
+		{ key: 'yards_allowed', label: 'YA' }
+	],
+	[Position.DL]: [
+		{ key: 'points', label: 'PTS' },
+		{ key: 'games', label: 'G' },
+		{ key: 'tackles', label: 'TKL' },
+		{ key: 'assists', label: 'AST' },
+		{ key: 'tackles_for_loss', label: 'TFL' },
+		{ key: 'qb_hits', label: 'QBH' },
+		{ key: 'idp_sacks', label: 'SACK' },
+		{ key: 'passes_defended', label: 'PD' },
+		{ key: 'idp_int', label: 'INT' },
+		{ key: 'idp_fumbles_forced', label: 'FF' },
+		{ key: 'idp_fumbles_recovered', label: 'FR' },
+		{ key: 'idp_td', label: 'DTD' }
+	],
+	[Position.LB]: [
+		{ key: 'points', label: 'PTS' },
+		{ key: 'games', label: 'G' },
+		{ key: 'tackles', label: 'TKL' },
+		{ key: 'assists', label: 'AST' },
+		{ key: 'tackles_for_loss', label: 'TFL' },
+		{ key: 'qb_hits', label: 'QBH' },
+		{ key: 'idp_sacks', label: 'SACK' },
+		{ key: 'passes_defended', label: 'PD' },
+		{ key: 'idp_int', label: 'INT' },
+		{ key: 'idp_fumbles_forced', label: 'FF' },
+		{ key: 'idp_fumbles_recovered', label: 'FR' },
+		{ key: 'idp_td', label: 'DTD' }
+	],
+	[Position.DB]: [
+		{ key: 'points', label: 'PTS' },
+		{ key: 'games', label: 'G' },
+		{ key: 'tackles', label: 'TKL' },
+		{ key: 'assists', label: 'AST' },
+		{ key: 'tackles_for_loss', label: 'TFL' },
+		{ key: 'qb_hits', label: 'QBH' },
+		{ key: 'idp_sacks', label: 'SACK' },
+		{ key: 'passes_defended', label: 'PD' },
+		{ key: 'idp_int', label: 'INT' },
+		{ key: 'idp_fumbles_forced', label: 'FF' },
+		{ key: 'idp_fumbles_recovered', label: 'FR' },
+		{ key: 'idp_td', label: 'DTD' }
 	],
 	[Position.ALL]: []
 };
@@ -4,6 +4,18 @@ export enum ScoringSettings {
 	PPR = 'PPR'
 }
 
+export enum LeagueFormat {
+	OneQB = 'OneQB',
+	Superflex = 'Superflex'
+}
+
+export enum RankingType {
+	Redraft = 'Redraft',
+	Dynasty = 'Dynasty',
+	Rookie = 'Rookie',
+	BestBall = 'BestBall'
+}
+
 export enum Team {
 	ALL = 'ALL',
 	ARI = 'ARI',
@@ -48,5 +60,8 @@ export enum Position {
 	WR = 'WR',
 	TE = 'TE',
 	K = 'K',
-	DST = 'DST'
+	DST = 'DST',
+	DL = 'DL',
+	LB = 'LB',
+	DB = 'DB'
 }
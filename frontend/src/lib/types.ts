@@ -1,4 +1,4 @@
-import type { Position, ScoringSettings, Team } from './enums';
+import type { LeagueFormat, Position, RankingType, ScoringSettings, Team } from './enums';
 
 export type Rankings = {
 	overall: number | null;
@@ -7,6 +7,8 @@ export type Rankings = {
 	worst: number | null;
 	average: number | null;
 	standard_deviation: number | null;
+	tier: number | null;
+	overall_tier: number | null;
 };
 
 export const defaultRankings: Rankings = {
@@ -15,7 +17,9 @@ export const defaultRankings: Rankings = {
 	best: null,
 	worst: null,
 	average: null,
-	standard_deviation: null
+	standard_deviation: null,
+	tier: null,
+	overall_tier: null
 };
 
 export type Stats = {
@@ -61,9 +65,23 @@ export type Stats = {
 	def_td: number | null;
 	safeties: number | null;
 	special_teams_td: number | null;
+	points_allowed: number | null;
+	yards_allowed: number | null;
+	tackles: number | null;
+	assists: number | null;
+	tackles_for_loss: number | null;
+	qb_hits: number | null;
+	passes_defended: number | null;
+	idp_sacks: number | null;
+	idp_int: number | null;
+	idp_fumbles_forced: number | null;
+	idp_fumbles_recovered: number | null;
+	idp_td: number | null;
 	games: number | null;
 	points: number | null;
 	points_per_game: number | null;
+	spike_weeks?: number;
+	best_ball_points?: number;
 };
 
 export const defaultStats: Stats = {
@@ -108,6 +126,18 @@ export const defaultStats: Stats = {
 	def_td: null,
 	safeties: null,
 	special_teams_td: null,
+	points_allowed: null,
+	yards_allowed: null,
+	tackles: null,
+	assists: null,
+	tackles_for_loss: null,
+	qb_hits: null,
+	passes_defended: null,
+	idp_sacks: null,
+	idp_int: null,
+	idp_fumbles_forced: null,
+	idp_fumbles_recovered: null,
+	idp_td: null,
 	games: null,
 	points: null,
 	points_per_game: null
@@ -125,7 +155,14 @@ export type Player = {
 	college: string;
 	rankings: Rankings;
 	stats: Stats;
+	projections: Stats;
 	drafted: boolean;
+	drafted_by_me: boolean;
+	keeper_round: number | null;
+	keeper_value: number | null;
+	auction_value: number | null;
+	vorp: number | null;
+	vbd_rank: number | null;
 };
 
 export const defaultPlayer: Player = {
@@ -140,12 +177,23 @@ export const defaultPlayer: Player = {
 	college: '',
 	rankings: defaultRankings,
 	stats: defaultStats,
-	drafted: false
+	projections: defaultStats,
+	drafted: false,
+	drafted_by_me: false,
+	keeper_round: null,
+	keeper_value: null,
+	auction_value: null,
+	vorp: null,
+	vbd_rank: null
 };
 
 export interface User {
 	id: string;
 	username: string;
 	scoring_settings: ScoringSettings;
+	league_format: LeagueFormat;
+	ranking_type: RankingType;
+	scoring_profile_id: number | null;
+	idp: boolean;
 	created_at: string;
 }